@@ -0,0 +1,164 @@
+use std::io::{self, IsTerminal, Stdout, Write};
+use std::time::{Duration, Instant};
+use crossterm::{cursor, execute, queue, terminal};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use grid::Simulation;
+
+const HELP: &str = "space: play/pause, right or .: step, +/-: speed, q: quit";
+const MAX_FPS: f64 = 1000.0;
+const MIN_FPS: f64 = 0.25;
+
+/// Plays a simulation in the terminal, redrawing each step in place, until the user quits
+pub struct Animation {
+    fps: f64,
+    paused: bool,
+    steps: usize,
+    finished: bool
+}
+
+enum Key {
+    PlayPause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+    Other
+}
+
+impl Animation {
+    pub fn new(fps: f64, paused: bool) -> Self {
+        Self {
+            fps: fps.clamp(MIN_FPS, MAX_FPS),
+            paused,
+            steps: 0,
+            finished: false
+        }
+    }
+
+    /// Animate until the user quits, returning the number of steps taken
+    pub fn play<S: Simulation>(&mut self, simulation: &mut S) -> io::Result<usize> {
+        let mut stdout = io::stdout();
+        if !stdout.is_terminal() {
+            return Err(io::Error::other("animations need a terminal"));
+        }
+        let _screen = Screen::enter(&mut stdout)?;
+        self.draw(&mut stdout, simulation)?;
+        let mut next_frame = Instant::now() + self.frame_time();
+        loop {
+            let timeout = if self.paused || self.finished {
+                Duration::from_secs(3600)
+            } else {
+                next_frame.saturating_duration_since(Instant::now())
+            };
+            if event::poll(timeout)? {
+                match read_key(event::read()?) {
+                    Key::PlayPause => self.paused = !self.paused,
+                    Key::Step => {
+                        self.paused = true;
+                        self.step(simulation);
+                    },
+                    Key::Faster => self.fps = (self.fps * 2.0).min(MAX_FPS),
+                    Key::Slower => self.fps = (self.fps / 2.0).max(MIN_FPS),
+                    Key::Quit => return Ok(self.steps),
+                    Key::Other => continue
+                }
+                next_frame = Instant::now() + self.frame_time();
+            } else {
+                self.step(simulation);
+                // from now rather than the last frame, so slow steps don't build up frames to catch up on
+                next_frame = Instant::now() + self.frame_time();
+            }
+            self.draw(&mut stdout, simulation)?;
+        }
+    }
+
+    fn frame_time(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+
+    fn step<S: Simulation>(&mut self, simulation: &mut S) {
+        if !self.finished {
+            if simulation.step() {
+                self.steps += 1;
+            } else {
+                self.finished = true;
+            }
+        }
+    }
+
+    fn status<S: Simulation>(&self, simulation: &S) -> String {
+        let state = if self.finished {
+            "finished"
+        } else if self.paused {
+            "paused"
+        } else {
+            "playing"
+        };
+        let mut status = format!("Step {} ({}, {} fps)", self.steps, state, self.fps);
+        let extra = simulation.status();
+        if !extra.is_empty() {
+            status.push(' ');
+            status.push_str(&extra);
+        }
+        status
+    }
+
+    // the frame is cut off at the edges of the terminal, rather than wrapping or scrolling
+    fn draw<S: Simulation>(&self, stdout: &mut Stdout, simulation: &S) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+        let frame = simulation.frame();
+        let mut row = 0;
+        for line in frame.lines().take(height.saturating_sub(2)) {
+            queue!(stdout, cursor::MoveTo(0, row), Print(truncate(line, width)), terminal::Clear(terminal::ClearType::UntilNewLine))?;
+            row += 1;
+        }
+        queue!(stdout, terminal::Clear(terminal::ClearType::FromCursorDown))?;
+        for line in [self.status(simulation), HELP.to_string()] {
+            queue!(stdout, cursor::MoveTo(0, row), Print(truncate(&line, width)), terminal::Clear(terminal::ClearType::UntilNewLine))?;
+            row += 1;
+        }
+        stdout.flush()
+    }
+}
+
+fn truncate(line: &str, width: usize) -> &str {
+    match line.char_indices().nth(width) {
+        Some((i, _)) => &line[..i],
+        None => line
+    }
+}
+
+fn read_key(event: Event) -> Key {
+    match event {
+        Event::Key(KeyEvent { code, modifiers, kind: KeyEventKind::Press, .. }) => match code {
+            KeyCode::Char(' ') => Key::PlayPause,
+            KeyCode::Right | KeyCode::Char('.') => Key::Step,
+            KeyCode::Char('+') | KeyCode::Char('=') => Key::Faster,
+            KeyCode::Char('-') => Key::Slower,
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => Key::Quit,
+            KeyCode::Char('q') | KeyCode::Esc => Key::Quit,
+            _ => Key::Other
+        },
+        _ => Key::Other
+    }
+}
+
+/// The alternate screen in raw mode, which is put back how it was when dropped (even if drawing fails)
+struct Screen;
+
+impl Screen {
+    fn enter(stdout: &mut Stdout) -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
@@ -0,0 +1,81 @@
+use std::env;
+use common::read_input;
+use grid::Simulation;
+use animate::Animation;
+
+const USAGE: &str = "Usage:
+    animate --day 6|14|15|18 [--input FILE|-] [--fps F] [--paused] [--big]
+(input defaults to dayN/input.txt, fps defaults to 10, --big animates the wide warehouse of day 15 part 2)";
+
+struct Options {
+    day: usize,
+    input: Option<String>,
+    fps: f64,
+    paused: bool,
+    big: bool
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let result = parse(&args[1..]).and_then(run);
+    match result {
+        Ok(steps) => println!("Animated {} steps", steps),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn parse(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        day: 0,
+        input: None,
+        fps: 10.0,
+        paused: false,
+        big: false
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--paused" => options.paused = true,
+            "--big" => options.big = true,
+            "--day" | "--input" | "--fps" => {
+                let value = args.next().ok_or(format!("Missing value for {}", arg))?;
+                match arg.as_str() {
+                    "--day" => options.day = value.parse().map_err(|_| format!("Invalid number for {}: {}", arg, value))?,
+                    "--fps" => options.fps = value.parse().map_err(|_| format!("Invalid number for {}: {}", arg, value))?,
+                    _ => options.input = Some(value.clone())
+                }
+            },
+            other => return Err(format!("Unknown argument: {}\n{}", other, USAGE))
+        }
+    }
+    if options.day == 0 {
+        return Err(USAGE.to_string());
+    }
+    if options.fps <= 0.0 {
+        return Err("--fps must be more than 0".to_string());
+    }
+    Ok(options)
+}
+
+fn run(options: Options) -> Result<usize, String> {
+    let filename = options.input.clone().unwrap_or(format!("day{}/input.txt", options.day));
+    let text = read_input(&filename).map_err(|_| format!("Error reading from {}", filename))?;
+    let invalid = |e: common::ParseError| e.in_file(&filename).to_string();
+    let mut animation = Animation::new(options.fps, options.paused);
+    match (options.day, options.big) {
+        (6, false) => play(&mut animation, day6::Patrol::new(text.parse().map_err(invalid)?)),
+        (14, false) => play(&mut animation, text.parse::<day14::Map>().map_err(invalid)?),
+        (15, false) => play(&mut animation, day15::warehouse(&text).map_err(invalid)?),
+        (15, true) => play(&mut animation, day15::big_warehouse(&text).map_err(invalid)?),
+        (18, false) => play(&mut animation, day18::FallingBytes::new(&text).map_err(invalid)?),
+        (_, true) => Err("--big only applies to day 15".to_string()),
+        (day, _) => Err(format!("No animation for day {}", day))
+    }
+}
+
+fn play<S: Simulation>(animation: &mut Animation, mut simulation: S) -> Result<usize, String> {
+    animation.play(&mut simulation).map_err(|e| format!("Error animating: {}", e))
+}
@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use serde::{Deserialize, Serialize};
+use common::STDIN;
+
+pub const DEFAULT_FILE: &str = "answers.toml";
+
+/// How an answer compares to the one recorded for the same day, part and input
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    New,
+    /// Stopped before it gave an answer, after the given number of seconds
+    Timeout { seconds: f64 }
+}
+
+impl Status {
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Pass => "PASS".to_string(),
+            Self::Fail { expected } => format!("FAIL, expected {}", expected),
+            Self::New => "NEW".to_string(),
+            Self::Timeout { seconds } => format!("TIMEOUT after {}s", seconds)
+        }
+    }
+}
+
+// the layout of the file, a list rather than nested tables so that it stays in order of day, input and part
+#[derive(Serialize, Deserialize, Default)]
+struct AnswersFile {
+    #[serde(default)]
+    answer: Vec<Recorded>
+}
+
+#[derive(Serialize, Deserialize)]
+struct Recorded {
+    day: usize,
+    input: String,
+    part: usize,
+    answer: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    known_bad: bool
+}
+
+/// The right answer, and whether the solver is known to get it wrong (or never finish)
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Expected {
+    answer: String,
+    known_bad: bool
+}
+
+/// Answers which are known to be right, keyed by day, input file and part
+pub struct Answers {
+    path: String,
+    recorded: BTreeMap<(usize, String, usize), Expected>
+}
+
+impl Answers {
+    /// Read the recorded answers, or start with none if the file doesn't exist yet
+    pub fn load(path: &str) -> Result<Self, String> {
+        let file: AnswersFile = match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text).map_err(|e| format!("Error reading {}: {}", path, e))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => AnswersFile::default(),
+            Err(e) => return Err(format!("Error reading {}: {}", path, e))
+        };
+        Ok(Self {
+            path: path.to_string(),
+            recorded: file.answer.into_iter().map(|r| ((r.day, key(&r.input), r.part), Expected {
+                answer: r.answer,
+                known_bad: r.known_bad
+            })).collect()
+        })
+    }
+
+    /// Compare with the recorded answer, unless the input came from stdin (which can't be recognised again)
+    pub fn check(&self, day: usize, input: &str, part: usize, answer: &str) -> Option<Status> {
+        if input == STDIN {
+            return None;
+        }
+        Some(match self.recorded.get(&(day, key(input), part)) {
+            None => Status::New,
+            Some(expected) if expected.answer == answer => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.answer.clone()
+            }
+        })
+    }
+
+    pub fn is_recorded(&self, day: usize, input: &str, part: usize) -> bool {
+        self.recorded.contains_key(&(day, key(input), part))
+    }
+
+    /// Whether the answer is marked `known_bad`, so that a FAIL or TIMEOUT is reported but doesn't fail the run
+    pub fn is_known_bad(&self, day: usize, input: &str, part: usize) -> bool {
+        self.recorded.get(&(day, key(input), part)).is_some_and(|e| e.known_bad)
+    }
+
+    pub fn record(&mut self, day: usize, input: &str, part: usize, answer: &str) {
+        self.recorded.insert((day, key(input), part), Expected {
+            answer: answer.to_string(),
+            known_bad: false
+        });
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let file = AnswersFile {
+            answer: self.recorded.iter().map(|((day, input, part), expected)| Recorded {
+                day: *day,
+                input: input.clone(),
+                part: *part,
+                answer: expected.answer.clone(),
+                known_bad: expected.known_bad
+            }).collect()
+        };
+        let text = toml::to_string(&file).map_err(|e| format!("Error writing {}: {}", self.path, e))?;
+        fs::write(&self.path, text).map_err(|e| format!("Error writing {}: {}", self.path, e))
+    }
+}
+
+/// The same file can be given as day6/input.txt or ./day6/input.txt, so answers are kept under the first
+pub fn key(input: &str) -> String {
+    input.trim_start_matches("./").to_string()
+}
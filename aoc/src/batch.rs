@@ -0,0 +1,61 @@
+use std::collections::BTreeSet;
+use std::fs;
+use crate::report::DayReport;
+
+/// Every example input in a day's directory (any .txt other than the official input.txt), in order of name
+pub fn examples(day: usize) -> Result<Vec<String>, String> {
+    let dir = format!("day{}", day);
+    let entries = fs::read_dir(&dir).map_err(|e| format!("Error reading {}: {}", dir, e))?;
+    let mut examples: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|name| name.ends_with(".txt") && name != "input.txt")
+        .map(|name| format!("{}/{}", dir, name))
+        .collect();
+    examples.sort();
+    Ok(examples)
+}
+
+/// A row per input with the answer (and its status) for each part and the total time taken, or the error which stopped it being solved
+pub fn table(rows: &[Result<DayReport, (String, String)>]) -> String {
+    let parts: BTreeSet<usize> = rows.iter().flatten().flat_map(|r| r.parts.iter().map(|p| p.part)).collect();
+    let mut header = vec!["File".to_string()];
+    header.extend(parts.iter().map(|p| format!("Part {}", p)));
+    header.push("Time".to_string());
+    let mut lines = vec![header];
+    for row in rows {
+        lines.push(match row {
+            Ok(report) => {
+                let mut cells = vec![report.input.clone()];
+                for part in &parts {
+                    cells.push(match report.parts.iter().find(|p| p.part == *part) {
+                        Some(p) => p.describe(),
+                        None => String::new()
+                    });
+                }
+                cells.push(format!("{:.3}s", report.parts.iter().map(|p| p.seconds).sum::<f64>()));
+                cells
+            },
+            // the error is too long to fit a column, so it fills the rest of the row
+            Err((input, error)) => vec![input.clone(), format!("ERROR {}", error)]
+        });
+    }
+    let columns = lines[0].len();
+    let mut widths = vec![0; columns];
+    for cells in &lines {
+        if cells.len() == columns {
+            for (width, cell) in widths.iter_mut().zip(cells) {
+                *width = (*width).max(cell.chars().count());
+            }
+        } else {
+            widths[0] = widths[0].max(cells[0].chars().count());
+        }
+    }
+    let mut text = String::new();
+    for cells in lines {
+        let padded: Vec<String> = cells.iter().zip(&widths).map(|(cell, width)| format!("{:<1$}", cell, width)).collect();
+        text.push_str(padded.join("  ").trim_end());
+        text.push('\n');
+    }
+    text
+}
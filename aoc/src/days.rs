@@ -0,0 +1,130 @@
+use common::ParseError;
+
+pub type Solver = Box<dyn Fn(&str) -> Result<String, ParseError>>;
+
+/// Anything a part can return, converted into the text of an answer
+pub trait Answer {
+    fn answer(self) -> Result<String, ParseError>;
+}
+
+impl Answer for usize {
+    fn answer(self) -> Result<String, ParseError> {
+        Ok(self.to_string())
+    }
+}
+
+impl Answer for u32 {
+    fn answer(self) -> Result<String, ParseError> {
+        Ok(self.to_string())
+    }
+}
+
+impl Answer for u128 {
+    fn answer(self) -> Result<String, ParseError> {
+        Ok(self.to_string())
+    }
+}
+
+impl Answer for i128 {
+    fn answer(self) -> Result<String, ParseError> {
+        Ok(self.to_string())
+    }
+}
+
+impl Answer for String {
+    fn answer(self) -> Result<String, ParseError> {
+        Ok(self)
+    }
+}
+
+impl<T: Answer> Answer for Option<T> {
+    fn answer(self) -> Result<String, ParseError> {
+        match self {
+            Some(a) => a.answer(),
+            None => Ok("No solution".to_string())
+        }
+    }
+}
+
+impl<T: Answer> Answer for Result<T, ParseError> {
+    fn answer(self) -> Result<String, ParseError> {
+        self?.answer()
+    }
+}
+
+pub struct Day {
+    pub number: usize,
+    /// Every problem with an input, without solving it
+    pub check: fn(&str) -> Vec<ParseError>,
+    pub parts: Vec<Solver>,
+    /// Extra named values which help explain the answers (only reported in json)
+    pub diagnostics: Vec<(&'static str, Solver)>
+}
+
+impl Day {
+    fn new<A: Answer + 'static, B: Answer + 'static>(number: usize, check: fn(&str) -> Vec<ParseError>, part1: fn(&str) -> A, part2: fn(&str) -> B) -> Self {
+        Self {
+            number,
+            check,
+            parts: vec![solver(part1), solver(part2)],
+            diagnostics: Vec::new()
+        }
+    }
+
+    fn part1_only<A: Answer + 'static>(number: usize, check: fn(&str) -> Vec<ParseError>, part1: fn(&str) -> A) -> Self {
+        Self {
+            number,
+            check,
+            parts: vec![solver(part1)],
+            diagnostics: Vec::new()
+        }
+    }
+
+    fn diagnostic<T: Answer + 'static>(mut self, name: &'static str, f: fn(&str) -> T) -> Self {
+        self.diagnostics.push((name, solver(f)));
+        self
+    }
+
+    pub fn default_input(&self) -> String {
+        format!("day{}/input.txt", self.number)
+    }
+}
+
+fn solver<T: Answer + 'static>(part: fn(&str) -> T) -> Solver {
+    Box::new(move |input| part(input).answer())
+}
+
+/// Every implemented day, in order
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new(1, day1::check, day1::part1, day1::part2),
+        Day::new(2, day2::check, day2::part1, day2::part2),
+        Day::new(3, day3::check, day3::part1, day3::part2),
+        Day::new(4, day4::check, day4::part1, day4::part2),
+        Day::new(5, day5::check, day5::part1, day5::part2),
+        Day::new(6, day6::check, day6::part1, day6::part2),
+        Day::new(7, day7::check, day7::part1, day7::part2),
+        Day::new(8, day8::check, day8::part1, day8::part2),
+        Day::new(9, day9::check, day9::part1, day9::part2),
+        Day::new(10, day10::check, day10::part1, day10::part2),
+        Day::new(11, day11::check, day11::part1, day11::part2),
+        Day::new(12, day12::check, day12::part1, day12::part2),
+        Day::new(13, day13::check, day13::part1, day13::part2),
+        Day::new(14, day14::check, day14::part1, day14::part2),
+        Day::new(15, day15::check, day15::part1, day15::part2),
+        Day::new(16, day16::check, day16::part1, day16::part2),
+        Day::new(17, day17::check, day17::part1, day17::part2),
+        Day::new(18, day18::check, day18::part1, day18::part2),
+        Day::new(19, day19::check, day19::part1, day19::part2),
+        Day::new(20, day20::check, day20::part1, day20::part2).diagnostic("no_cheat_path", day20::no_cheat_path),
+        Day::new(21, day21::check, day21::part1, day21::part2),
+        Day::new(22, day22::check, day22::part1, day22::part2),
+        Day::new(23, day23::check, day23::part1, day23::part2),
+        Day::part1_only(24, day24::check, day24::part1).diagnostic("z_binary", day24::z_binary),
+        Day::part1_only(25, day25::check, day25::part1)
+    ]
+}
+
+pub fn find(number: usize) -> Option<Day> {
+    all().into_iter().find(|d| d.number == number)
+}
@@ -0,0 +1,8 @@
+//! The runner's answers, days and reports, as a library so that they can be tested
+
+pub mod answers;
+pub mod batch;
+pub mod days;
+pub mod progress;
+pub mod report;
+pub mod solve;
@@ -0,0 +1,421 @@
+use std::fs;
+use std::env;
+use std::time::{Duration, Instant};
+use common::{Level, STDIN, cache_flags, log, log_flags, read_input};
+use aoc::{answers, batch, days, progress, solve};
+use aoc::answers::{Answers, Status};
+use aoc::days::Day;
+use aoc::report::{DayReport, PartReport};
+use aoc::solve::Solved;
+
+/// How long each part gets with --all, --batch or progress, unless --timeout says otherwise
+const DEFAULT_TIMEOUT: f64 = 60.0;
+
+const USAGE: &str = "Usage:
+    aoc run --day N [--part P] [--input FILE|-] [--format text|json] [--answers FILE] [--record]
+    aoc run --all [--format text|json] [--answers FILE] [--record]
+    aoc run --day N [--input FILE|-] --check
+    aoc run --all --check
+    aoc run --day N|--all --batch [--part P] [--format text|json] [--answers FILE] [--record] [--check] [--timeout SECONDS]
+    aoc list
+    aoc progress [--answers FILE] [--write README.md] [--timeout SECONDS]
+--batch runs every example (each *.txt other than input.txt in the day's directory) and prints a table of the answers and times
+--timeout stops any part which takes longer (solving each in a process of its own), default 60 for --all, --batch and progress and none for a single --day (or 0 for none)
+--check reports every malformed line of the input(s) rather than solving them
+Each answer is checked against those recorded in answers.toml (PASS, FAIL or NEW), --record adds the NEW ones, and a FAIL or TIMEOUT of an answer marked known_bad doesn't fail the run
+progress solves each part with a recorded answer for its official input and prints a table of which PASS, or --write puts it between the <!-- progress --> markers of a file
+--verbose reports progress on stderr (twice for debug output), --quiet hides everything but the answers
+--cache-capacity N limits the memo caches of days 11, 19, 21 and 23 to N entries each, forgetting the least recently used (or the oldest with --cache-eviction fifo)";
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum Format {
+    Text,
+    Json
+}
+
+struct Run {
+    day: Option<usize>,
+    part: Option<usize>,
+    input: Option<String>,
+    all: bool,
+    format: Format,
+    answers: String,
+    record: bool,
+    check: bool,
+    batch: bool,
+    timeout: Option<Duration>,
+    /// The log and cache flags, passed on to the processes which solve parts when there is a timeout
+    flags: Vec<String>
+}
+
+impl Run {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut run = Self {
+            day: None,
+            part: None,
+            input: None,
+            all: false,
+            format: Format::Text,
+            answers: answers::DEFAULT_FILE.to_string(),
+            record: false,
+            check: false,
+            batch: false,
+            timeout: None,
+            flags: Vec::new()
+        };
+        let mut timeout = None;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => run.all = true,
+                "--record" => run.record = true,
+                "--check" => run.check = true,
+                "--batch" => run.batch = true,
+                "--answers" => run.answers = value_arg(arg, args.next())?.to_string(),
+                "--day" => run.day = Some(number_arg(arg, args.next())?),
+                "--part" => run.part = Some(number_arg(arg, args.next())?),
+                "--input" => run.input = Some(value_arg(arg, args.next())?.to_string()),
+                "--timeout" => timeout = Some(seconds_arg(arg, args.next())?),
+                "--format" => run.format = match value_arg(arg, args.next())? {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    other => return Err(format!("Unknown format: {} (expected text or json)", other))
+                },
+                other => return Err(format!("Unknown argument: {}", other))
+            }
+        }
+        if run.all == run.day.is_some() {
+            return Err("Please provide either --day or --all".to_string());
+        }
+        if run.all && (run.input.is_some() || (run.part.is_some() && !run.batch)) {
+            return Err("--part and --input can only be used with --day (or --part with --batch)".to_string());
+        }
+        if run.batch && run.input.is_some() {
+            return Err("--batch runs every example, so can't be used with --input".to_string());
+        }
+        if run.check && (run.part.is_some() || run.record || run.format == Format::Json) {
+            return Err("--check can't be used with --part, --record or --format json".to_string());
+        }
+        // so that one slow (or never ending) part can't hold up all the others
+        run.timeout = timeout.unwrap_or_else(|| (run.all || run.batch).then(|| Duration::from_secs_f64(DEFAULT_TIMEOUT)));
+        if run.timeout.is_some() && run.input.as_deref() == Some(STDIN) {
+            return Err("--timeout solves each part in a process of its own, so can't be used with input from stdin".to_string());
+        }
+        Ok(run)
+    }
+}
+
+fn value_arg<'a>(name: &str, value: Option<&'a String>) -> Result<&'a str, String> {
+    value.map(|s| s.as_str()).ok_or(format!("Missing value for {}", name))
+}
+
+// a number of seconds, where 0 means no limit
+fn seconds_arg(name: &str, value: Option<&String>) -> Result<Option<Duration>, String> {
+    let value = value_arg(name, value)?;
+    match value.parse::<f64>() {
+        Ok(0.0) => Ok(None),
+        Ok(seconds) if seconds > 0.0 => Ok(Some(Duration::from_secs_f64(seconds))),
+        _ => Err(format!("Invalid number of seconds for {}: {}", name, value))
+    }
+}
+
+fn number_arg(name: &str, value: Option<&String>) -> Result<usize, String> {
+    let value = value_arg(name, value)?;
+    value.parse().map_err(|_| format!("Invalid number for {}: {}", name, value))
+}
+
+fn main() {
+    let raw: Vec<String> = env::args().collect();
+    let args = cache_flags(log_flags(raw.clone()));
+    let result = match args.get(1).map(|s| s.as_str()) {
+        Some("run") => Run::parse(&args[2..]).and_then(|mut r| {
+            r.flags = shared_flags(&raw);
+            run(r)
+        }),
+        Some("solve") => solve_part(&args[2..]),
+        Some("list") => {
+            list();
+            Ok(())
+        },
+        Some("progress") => progress(&args[2..], &shared_flags(&raw)),
+        _ => Err(USAGE.to_string())
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+// the log and cache flags (and their values) out of all the arguments
+fn shared_flags(args: &[String]) -> Vec<String> {
+    let mut flags = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-q" | "--quiet" | "-v" | "--verbose" => flags.push(arg.clone()),
+            "--cache-capacity" | "--cache-eviction" => {
+                flags.push(arg.clone());
+                flags.extend(args.next().cloned());
+            },
+            _ => {}
+        }
+    }
+    flags
+}
+
+/// Solve one part and print the seconds it took then the answer, for `solve::in_child` to read (not meant to be run by hand)
+fn solve_part(args: &[String]) -> Result<(), String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(number_arg(arg, args.next())?),
+            "--part" => part = Some(number_arg(arg, args.next())?),
+            "--input" => input = Some(value_arg(arg, args.next())?),
+            other => return Err(format!("Unknown argument: {}", other))
+        }
+    }
+    let (Some(number), Some(part), Some(input)) = (day, part, input) else {
+        return Err("solve needs --day, --part and --input".to_string());
+    };
+    let day = days::find(number).ok_or(format!("Day {} is not implemented", number))?;
+    let solver = day.parts.get(part.wrapping_sub(1)).ok_or(format!("Day {} has no part {}", number, part))?;
+    let text = read_input(input)
+        .map_err(|_| format!("Error reading from {}", input))?;
+    let start = Instant::now();
+    let answer = solver(&text).map_err(|e| e.in_file(input).to_string())?;
+    println!("{}\n{}", start.elapsed().as_secs_f64(), answer);
+    Ok(())
+}
+
+fn list() {
+    for day in days::all() {
+        println!("Day {}: {} part(s)", day.number, day.parts.len());
+    }
+}
+
+fn progress(args: &[String], flags: &[String]) -> Result<(), String> {
+    let mut answers = answers::DEFAULT_FILE;
+    let mut write = None;
+    let mut timeout = Some(Duration::from_secs_f64(DEFAULT_TIMEOUT));
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => answers = value_arg(arg, args.next())?,
+            "--write" => write = Some(value_arg(arg, args.next())?),
+            "--timeout" => timeout = seconds_arg(arg, args.next())?,
+            other => return Err(format!("Unknown argument: {}", other))
+        }
+    }
+    let passing = progress::passing(&Answers::load(answers)?, timeout, flags);
+    let table = progress::table(&passing);
+    match write {
+        Some(path) => {
+            progress::write(path, &table)?;
+            log(Level::Normal, format_args!("Updated the progress table in {}", path));
+        },
+        None => print!("{}", table)
+    }
+    Ok(())
+}
+
+/// The day(s) to run, and the input file(s) to run each of them on
+fn inputs(run: &Run) -> Result<Vec<(Day, Vec<String>)>, String> {
+    let days = match run.day {
+        Some(number) => vec![days::find(number).ok_or(format!("Day {} is not implemented", number))?],
+        None => days::all()
+    };
+    let mut inputs = Vec::new();
+    for day in days {
+        if run.batch {
+            let examples = batch::examples(day.number)?;
+            if examples.is_empty() {
+                log(Level::Normal, format_args!("Day {}: no examples", day.number));
+            }
+            inputs.push((day, examples));
+        } else if let Some(filename) = &run.input {
+            inputs.push((day, vec![filename.clone()]));
+        } else {
+            let filename = day.default_input();
+            if run.all && fs::metadata(&filename).is_err() {
+                log(Level::Normal, format_args!("Day {}: no {}", day.number, filename));
+            } else {
+                inputs.push((day, vec![filename]));
+            }
+        }
+    }
+    Ok(inputs)
+}
+
+fn run(run: Run) -> Result<(), String> {
+    if run.check {
+        return check(run);
+    }
+    let mut answers = Answers::load(&run.answers)?;
+    let mut reports = Vec::new();
+    let mut errors = 0;
+    for (day, filenames) in inputs(&run)? {
+        let mut rows = Vec::new();
+        for filename in filenames {
+            match run_day(&run, &day, &filename, &answers) {
+                Ok(report) => {
+                    rows.push(Ok(report.clone()));
+                    reports.push(report);
+                },
+                // one broken example shouldn't stop the rest of the batch
+                Err(e) if run.batch => rows.push(Err((filename, e))),
+                Err(e) => return Err(e)
+            }
+        }
+        errors += rows.iter().filter(|r| r.is_err()).count();
+        // a table per day, so that each is seen as soon as it is done
+        if run.batch && run.format == Format::Text && !rows.is_empty() {
+            println!("Day {}:\n{}", day.number, batch::table(&rows));
+        }
+    }
+    if run.format == Format::Json {
+        if run.all || run.batch {
+            println!("{}", to_json(&reports)?);
+        } else {
+            println!("{}", to_json(&reports[0])?);
+        }
+    }
+    verify(&reports, &mut answers, run.record, &run.answers)?;
+    if errors > 0 {
+        Err(format!("{} input(s) couldn't be solved", errors))
+    } else {
+        Ok(())
+    }
+}
+
+/// Report every problem with the input(s) of the requested day(s), failing if there were any
+fn check(run: Run) -> Result<(), String> {
+    let mut problems = 0;
+    for (day, filenames) in inputs(&run)? {
+        for filename in filenames {
+            let text = read_input(&filename)
+                .map_err(|_| format!("Error reading from {}", filename))?;
+            let errors = (day.check)(&text);
+            for error in &errors {
+                println!("{}", error.clone().in_file(&filename));
+            }
+            if errors.is_empty() {
+                println!("Day {}: {} OK", day.number, filename);
+            } else {
+                println!("Day {}: {} has {} problem(s)", day.number, filename, errors.len());
+            }
+            problems += errors.len();
+        }
+    }
+    if problems > 0 {
+        Err(format!("{} problem(s) found", problems))
+    } else {
+        Ok(())
+    }
+}
+
+/// Record any new answers (if asked to), then fail if any answers have changed (other than those known to be bad)
+fn verify(reports: &[DayReport], answers: &mut Answers, record: bool, path: &str) -> Result<(), String> {
+    let mut new = 0;
+    let mut failed = 0;
+    let mut timed_out = 0;
+    let mut known_bad = 0;
+    for report in reports {
+        for part in &report.parts {
+            match part.status {
+                Some(Status::New) => {
+                    new += 1;
+                    if record {
+                        answers.record(report.day, &report.input, part.part, &part.answer);
+                    }
+                },
+                Some(Status::Fail { .. } | Status::Timeout { .. }) if part.known_bad => known_bad += 1,
+                Some(Status::Fail { .. }) => failed += 1,
+                Some(Status::Timeout { .. }) => timed_out += 1,
+                _ => {}
+            }
+        }
+    }
+    if record && new > 0 {
+        answers.save()?;
+        log(Level::Normal, format_args!("Recorded {} new answer(s) in {}", new, path));
+    }
+    if known_bad > 0 {
+        log(Level::Normal, format_args!("{} answer(s) known to be bad in {} didn't count as failures", known_bad, path));
+    }
+    let mut problems = Vec::new();
+    if failed > 0 {
+        problems.push(format!("{} answer(s) differ from {}", failed, path));
+    }
+    if timed_out > 0 {
+        problems.push(format!("{} part(s) timed out", timed_out));
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems.join(", "))
+    }
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, String> {
+    serde_json::to_string_pretty(value).map_err(|e| format!("Error writing json: {}", e))
+}
+
+/// Solve the requested part(s), printing each answer as it is found (unless in a batch or json)
+fn run_day(run: &Run, day: &Day, filename: &str, answers: &Answers) -> Result<DayReport, String> {
+    let echo = run.format == Format::Text && !run.batch;
+    let diagnostics = run.format == Format::Json;
+    let text = read_input(filename)
+        .map_err(|_| format!("Error reading from {}", filename))?;
+    let parts: Vec<usize> = match run.part {
+        Some(p) if p == 0 || p > day.parts.len() => return Err(format!("Day {} has no part {}", day.number, p)),
+        Some(p) => vec![p],
+        None => (1..=day.parts.len()).collect()
+    };
+    let mut report = DayReport {
+        day: day.number,
+        input: filename.to_string(),
+        parts: Vec::new(),
+        diagnostics: Default::default()
+    };
+    for p in parts {
+        let (answer, seconds, status) = match run.timeout {
+            Some(limit) => match solve::in_child(day.number, p, filename, &run.flags, limit)? {
+                Solved::Answer { answer, seconds } => {
+                    let status = answers.check(day.number, filename, p, &answer);
+                    (answer, seconds, status)
+                },
+                Solved::TimedOut => (String::new(), limit.as_secs_f64(), Some(Status::Timeout {
+                    seconds: limit.as_secs_f64()
+                }))
+            },
+            None => {
+                let start = Instant::now();
+                let answer = day.parts[p - 1](&text).map_err(|e| e.in_file(filename).to_string())?;
+                let seconds = start.elapsed().as_secs_f64();
+                let status = answers.check(day.number, filename, p, &answer);
+                (answer, seconds, status)
+            }
+        };
+        let part = PartReport {
+            part: p,
+            answer,
+            seconds,
+            status,
+            known_bad: answers.is_known_bad(day.number, filename, p)
+        };
+        if echo {
+            println!("Day {} part {}: {}", day.number, p, part.describe());
+        }
+        report.parts.push(part);
+    }
+    if diagnostics {
+        for (name, diagnostic) in &day.diagnostics {
+            let value = diagnostic(&text).map_err(|e| e.in_file(filename).to_string())?;
+            report.diagnostics.insert(name, value);
+        }
+    }
+    Ok(report)
+}
@@ -0,0 +1,85 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::time::Duration;
+use common::{Level, log, read_input};
+use crate::answers::{Answers, Status};
+use crate::days;
+use crate::solve::{self, Solved};
+
+/// The days of the calendar, whether or not they have been started
+const DAYS: usize = 25;
+/// Where the generated table goes in the README, replacing anything already between the markers
+const START: &str = "<!-- progress -->";
+const END: &str = "<!-- /progress -->";
+
+/// The (day, part) of each part whose answer for the official input is recorded and still PASSes
+///
+/// Each part with a recorded answer is solved again, in a process of its own if there is a `timeout` (with the log and cache `flags`).
+pub fn passing(answers: &Answers, timeout: Option<Duration>, flags: &[String]) -> BTreeSet<(usize, usize)> {
+    let mut passing = BTreeSet::new();
+    for day in days::all() {
+        let input = day.default_input();
+        for part in 1..=day.parts.len() {
+            if !answers.is_recorded(day.number, &input, part) {
+                continue;
+            }
+            let answer = match timeout {
+                Some(limit) => match solve::in_child(day.number, part, &input, flags, limit) {
+                    Ok(Solved::Answer { answer, .. }) => Ok(answer),
+                    Ok(Solved::TimedOut) => Err(format!("timed out after {}s", limit.as_secs_f64())),
+                    Err(e) => Err(e)
+                },
+                None => read_input(&input)
+                    .map_err(|_| format!("Error reading from {}", input))
+                    .and_then(|text| day.parts[part - 1](&text).map_err(|e| e.in_file(&input).to_string()))
+            };
+            match answer.map(|answer| answers.check(day.number, &input, part, &answer)) {
+                Ok(Some(Status::Pass)) => {
+                    log(Level::Normal, format_args!("Day {} part {}: PASS", day.number, part));
+                    passing.insert((day.number, part));
+                },
+                Ok(status) => log(Level::Normal, format_args!("Day {} part {}: {}", day.number, part, status.map_or("NEW".to_string(), |s| s.describe()))),
+                Err(e) => log(Level::Normal, format_args!("Day {} part {}: {}", day.number, part, e))
+            }
+        }
+    }
+    passing
+}
+
+/// A markdown table of every day, where a part is ticked once its answer for the official input is recorded and `passing` (so checked by every run)
+pub fn table(passing: &BTreeSet<(usize, usize)>) -> String {
+    let implemented = days::all();
+    let mut table = "| Day | Part 1 | Part 2 |\n|-----|:------:|:------:|\n".to_string();
+    for number in 1..=DAYS {
+        let cells: Vec<&str> = match implemented.iter().find(|d| d.number == number) {
+            Some(day) => (1..=2).map(|part| if part > day.parts.len() {
+                " "
+            } else if passing.contains(&(number, part)) {
+                "✓"
+            } else {
+                "✗"
+            }).collect(),
+            None => vec![" ", " "]
+        };
+        let day = if implemented.iter().any(|d| d.number == number) {
+            format!("[{}](day{}/src/lib.rs)", number, number)
+        } else {
+            number.to_string()
+        };
+        table.push_str(&format!("| {} | {} | {} |\n", day, cells[0], cells[1]));
+    }
+    table
+}
+
+/// Replace the table between the progress markers of a markdown file
+pub fn write(path: &str, table: &str) -> Result<(), String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("Error reading {}: {}", path, e))?;
+    let (Some(start), Some(end)) = (text.find(START), text.find(END)) else {
+        return Err(format!("{} has no {} and {} markers to put the table between", path, START, END));
+    };
+    if end < start {
+        return Err(format!("{} has {} before {}", path, END, START));
+    }
+    let text = format!("{}{}\n{}{}", &text[..start], START, table, &text[end..]);
+    fs::write(path, text).map_err(|e| format!("Error writing {}: {}", path, e))
+}
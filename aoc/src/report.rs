@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+use serde::Serialize;
+use crate::answers::Status;
+
+/// The results of running one day on one input, as reported in json
+#[derive(Serialize, Clone)]
+pub struct DayReport {
+    pub day: usize,
+    pub input: String,
+    pub parts: Vec<PartReport>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub diagnostics: BTreeMap<&'static str, String>
+}
+
+#[derive(Serialize, Clone)]
+pub struct PartReport {
+    pub part: usize,
+    pub answer: String,
+    /// Wall-clock time taken to parse the input and solve this part
+    pub seconds: f64,
+    /// Compared to the recorded answer (unless the input was stdin)
+    #[serde(flatten)]
+    pub status: Option<Status>,
+    /// The recorded answer is marked as one the solver gets wrong, so a FAIL or TIMEOUT doesn't fail the run
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub known_bad: bool
+}
+
+impl PartReport {
+    /// The answer and how it compares to the recorded one, eg. "161 (PASS)"
+    pub fn describe(&self) -> String {
+        let known_bad = if self.known_bad { ", known bad" } else { "" };
+        match &self.status {
+            Some(status @ Status::Timeout { .. }) if self.known_bad => format!("{} (known bad)", status.describe()),
+            Some(status @ Status::Timeout { .. }) => status.describe(),
+            Some(status) => format!("{} ({}{})", self.answer, status.describe(), known_bad),
+            None => self.answer.clone()
+        }
+    }
+}
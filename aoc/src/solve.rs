@@ -0,0 +1,86 @@
+use std::env;
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often to check whether a child has finished
+const POLL: Duration = Duration::from_millis(10);
+
+/// How solving a part in a child process ended
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solved {
+    Answer {
+        answer: String,
+        seconds: f64
+    },
+    TimedOut
+}
+
+/// Solve one part in a child process (`aoc solve`) with the same log and cache `flags`, killing it if it takes longer than `limit`
+///
+/// A part which never finishes can't be stopped from another thread, and may use more and more memory until it is, so it gets a process of its own.
+pub fn in_child(day: usize, part: usize, input: &str, flags: &[String], limit: Duration) -> Result<Solved, String> {
+    let exe = env::current_exe().map_err(|e| format!("Error finding the aoc executable: {}", e))?;
+    let mut child = Command::new(exe)
+        .args(flags)
+        .args(["solve", "--day", &day.to_string(), "--part", &part.to_string(), "--input", input])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Error starting day {} part {}: {}", day, part, e))?;
+    // both are read while the child runs, so that it can't fill a pipe and block (which would look like a timeout)
+    let stdout = read_all(child.stdout.take());
+    let stderr = read_all(child.stderr.take());
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| format!("Error waiting for day {} part {}: {}", day, part, e))? {
+            break Some(status);
+        }
+        if start.elapsed() > limit {
+            // it may have finished since, which is no different to being too late
+            let _ = child.kill();
+            let _ = child.wait();
+            break None;
+        }
+        thread::sleep(POLL);
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    // the child's progress and any other messages are passed on once it has finished
+    let (messages, error) = match stderr.trim_end().rsplit_once('\n') {
+        Some((messages, last)) => (messages, last),
+        None => ("", stderr.trim_end())
+    };
+    match status {
+        None => {
+            eprint!("{}", stderr);
+            Ok(Solved::TimedOut)
+        },
+        Some(status) if status.success() => {
+            eprint!("{}", stderr);
+            let (seconds, answer) = stdout.split_once('\n').ok_or(format!("Day {} part {} gave no answer", day, part))?;
+            Ok(Solved::Answer {
+                answer: answer.trim_end_matches('\n').to_string(),
+                seconds: seconds.parse().map_err(|_| format!("Day {} part {} gave no time", day, part))?
+            })
+        },
+        Some(_) => {
+            if !messages.is_empty() {
+                eprintln!("{}", messages);
+            }
+            Err(error.to_string())
+        }
+    }
+}
+
+fn read_all<R: Read + Send + 'static>(pipe: Option<R>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut text);
+        }
+        text
+    })
+}
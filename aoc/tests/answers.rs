@@ -0,0 +1,62 @@
+use std::env;
+use std::fs;
+use aoc::answers::{Answers, Status, key};
+
+fn temp(name: &str) -> String {
+    env::temp_dir().join(format!("aoc-answers-{}-{}.toml", name, std::process::id())).to_string_lossy().to_string()
+}
+
+#[test]
+fn test_key() {
+    assert_eq!(key("./day6/input.txt"), "day6/input.txt");
+    assert_eq!(key("day6/input.txt"), "day6/input.txt");
+    assert_eq!(key("/tmp/day6.txt"), "/tmp/day6.txt");
+}
+
+#[test]
+fn test_round_trip() {
+    let path = temp("round-trip");
+    let mut answers = Answers::load(&path).unwrap(); // doesn't exist yet, so there are none
+    assert_eq!(answers.check(6, "day6/input.txt", 1, "41"), Some(Status::New));
+    answers.record(6, "./day6/input.txt", 1, "41");
+    answers.record(6, "day6/input.txt", 2, "6");
+    answers.save().unwrap();
+    let saved = fs::read_to_string(&path).unwrap();
+    assert!(!saved.contains("./day6"));
+    assert!(!saved.contains("known_bad"));
+
+    let answers = Answers::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(answers.check(6, "./day6/input.txt", 1, "41"), Some(Status::Pass));
+    assert_eq!(answers.check(6, "day6/input.txt", 2, "7"), Some(Status::Fail {
+        expected: "6".to_string()
+    }));
+    assert_eq!(answers.check(6, "-", 1, "41"), None);
+    assert!(answers.is_recorded(6, "day6/input.txt", 2));
+    assert!(!answers.is_known_bad(6, "day6/input.txt", 2));
+}
+
+#[test]
+fn test_known_bad() {
+    let path = temp("known-bad");
+    fs::write(&path, "[[answer]]\nday = 21\ninput = \"./day21/test.txt\"\npart = 1\nanswer = \"126384\"\nknown_bad = true\n").unwrap();
+    let answers = Answers::load(&path).unwrap();
+    assert!(answers.is_known_bad(21, "day21/test.txt", 1));
+    assert!(!answers.is_known_bad(21, "day21/test.txt", 2));
+    answers.save().unwrap();
+    let saved = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(saved, "[[answer]]\nday = 21\ninput = \"day21/test.txt\"\npart = 1\nanswer = \"126384\"\nknown_bad = true\n");
+}
+
+#[test]
+fn test_recorded_file_round_trips() {
+    // saving the answers which are checked in changes nothing, so --record only ever adds to them
+    let original = fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml")).unwrap();
+    let path = temp("recorded");
+    fs::write(&path, &original).unwrap();
+    Answers::load(&path).unwrap().save().unwrap();
+    let saved = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(saved, original);
+}
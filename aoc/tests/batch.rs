@@ -0,0 +1,86 @@
+use std::env;
+use std::fs;
+use std::process::{Command, Output};
+use aoc::answers::Status;
+use aoc::batch::table;
+use aoc::report::{DayReport, PartReport};
+
+fn part(part: usize, answer: &str, status: Option<Status>) -> PartReport {
+    PartReport {
+        part,
+        answer: answer.to_string(),
+        seconds: 0.5,
+        status,
+        known_bad: false
+    }
+}
+
+fn report(input: &str, parts: Vec<PartReport>) -> DayReport {
+    DayReport {
+        day: 3,
+        input: input.to_string(),
+        parts,
+        diagnostics: Default::default()
+    }
+}
+
+#[test]
+fn test_table() {
+    let rows = vec![
+        Ok(report("day3/test.txt", vec![part(1, "161", Some(Status::Pass)), part(2, "48", Some(Status::Fail {
+            expected: "47".to_string()
+        }))])),
+        Ok(report("day3/long.txt", vec![part(1, "", Some(Status::Timeout {
+            seconds: 60.0
+        }))])),
+        Err(("day3/bad.txt".to_string(), "line 1: expected a number but found x".to_string()))
+    ];
+    assert_eq!(table(&rows), "\
+File           Part 1             Part 2                  Time
+day3/test.txt  161 (PASS)         48 (FAIL, expected 47)  1.000s
+day3/long.txt  TIMEOUT after 60s                          0.500s
+day3/bad.txt   ERROR line 1: expected a number but found x
+");
+}
+
+// run the runner from the top of the workspace, where the day directories are
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+        .output()
+        .unwrap()
+}
+
+fn answers(name: &str, text: &str) -> String {
+    let path = env::temp_dir().join(format!("aoc-batch-{}-{}.toml", name, std::process::id()));
+    fs::write(&path, text).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+#[test]
+fn test_exit_code() {
+    let passing = aoc(&["run", "--day", "3", "--batch", "-q"]);
+    assert!(passing.status.success(), "{}", String::from_utf8_lossy(&passing.stderr));
+    assert!(String::from_utf8_lossy(&passing.stdout).contains("161 (PASS)"));
+
+    let wrong = answers("wrong", "[[answer]]\nday = 3\ninput = \"day3/test.txt\"\npart = 1\nanswer = \"0\"\n");
+    let failing = aoc(&["run", "--day", "3", "--batch", "--part", "1", "--answers", &wrong, "-q"]);
+    fs::remove_file(&wrong).unwrap();
+    assert!(!failing.status.success());
+    assert!(String::from_utf8_lossy(&failing.stderr).contains("1 answer(s) differ"));
+
+    // day 21 part 2 never finishes on its example, which fails the run unless its answer is marked known_bad (as it is in answers.toml)
+    let none = answers("none", "");
+    let slow = aoc(&["run", "--day", "21", "--part", "2", "--batch", "--timeout", "1", "--answers", &none, "-q"]);
+    fs::remove_file(&none).unwrap();
+    assert!(!slow.status.success());
+    assert!(String::from_utf8_lossy(&slow.stdout).contains("TIMEOUT after 1s"));
+    assert!(String::from_utf8_lossy(&slow.stderr).contains("1 part(s) timed out"));
+    let known_bad = aoc(&["run", "--day", "21", "--batch", "--timeout", "1"]);
+    assert!(known_bad.status.success(), "{}", String::from_utf8_lossy(&known_bad.stderr));
+    assert!(String::from_utf8_lossy(&known_bad.stdout).contains("(FAIL, expected 126384, known bad)"));
+    assert!(String::from_utf8_lossy(&known_bad.stdout).contains("TIMEOUT after 1s (known bad)"));
+
+    assert!(!aoc(&["run", "--day", "99", "--batch"]).status.success());
+}
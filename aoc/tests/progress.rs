@@ -0,0 +1,36 @@
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use aoc::progress::{table, write};
+
+#[test]
+fn test_table() {
+    let passing = BTreeSet::from([(1, 1), (1, 2), (3, 1)]);
+    let text = table(&passing);
+    let rows: Vec<&str> = text.lines().collect();
+    assert_eq!(rows.len(), 2 + 25);
+    // a part is ticked only if it passes, whether or not it is recorded
+    assert_eq!(rows[2], "| [1](day1/src/lib.rs) | ✓ | ✓ |");
+    assert_eq!(rows[3], "| [2](day2/src/lib.rs) | ✗ | ✗ |");
+    assert_eq!(rows[4], "| [3](day3/src/lib.rs) | ✓ | ✗ |");
+    assert_eq!(table(&BTreeSet::new()).matches('✓').count(), 0);
+}
+
+#[test]
+fn test_write() {
+    let path = env::temp_dir().join(format!("aoc-progress-{}.md", std::process::id()));
+    let path = path.to_str().unwrap();
+    fs::write(path, "# Title\n\n<!-- progress -->\nold\ntable\n<!-- /progress -->\n\nAfter.\n").unwrap();
+    write(path, "| new |\n").unwrap();
+    assert_eq!(fs::read_to_string(path).unwrap(), "# Title\n\n<!-- progress -->\n| new |\n<!-- /progress -->\n\nAfter.\n");
+    // writing again gives the same text
+    write(path, "| new |\n").unwrap();
+    assert_eq!(fs::read_to_string(path).unwrap(), "# Title\n\n<!-- progress -->\n| new |\n<!-- /progress -->\n\nAfter.\n");
+
+    fs::write(path, "# Title\n<!-- /progress -->\n<!-- progress -->\n").unwrap();
+    assert!(write(path, "| new |\n").is_err());
+    fs::write(path, "# Title\n").unwrap();
+    assert!(write(path, "| new |\n").unwrap_err().contains("markers"));
+    assert_eq!(fs::read_to_string(path).unwrap(), "# Title\n");
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,192 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::process;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+/// Which entry a full cache forgets to make room for a new one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Eviction {
+    LeastRecentlyUsed,
+    FirstInFirstOut
+}
+
+static CAPACITY: AtomicUsize = AtomicUsize::new(usize::MAX);
+static EVICTION: AtomicU8 = AtomicU8::new(Eviction::LeastRecentlyUsed as u8);
+
+/// Set the capacity and eviction of every cache made by `Cache::new` from now on (None for unbounded)
+pub fn set_cache_defaults(capacity: Option<usize>, eviction: Eviction) {
+    CAPACITY.store(capacity.unwrap_or(usize::MAX), Ordering::Relaxed);
+    EVICTION.store(eviction as u8, Ordering::Relaxed);
+}
+
+/// Set the cache defaults from (and remove) any --cache-capacity N or --cache-eviction lru|fifo arguments
+pub fn cache_flags(args: Vec<String>) -> Vec<String> {
+    let mut capacity = None;
+    let mut eviction = Eviction::LeastRecentlyUsed;
+    let mut remaining = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--cache-capacity" => capacity = Some(args.next().and_then(|v| v.parse().ok()).unwrap_or_else(|| {
+                eprintln!("--cache-capacity should be followed by a number of entries");
+                process::exit(1);
+            })),
+            "--cache-eviction" => eviction = match args.next().as_deref() {
+                Some("lru") => Eviction::LeastRecentlyUsed,
+                Some("fifo") => Eviction::FirstInFirstOut,
+                _ => {
+                    eprintln!("--cache-eviction should be followed by lru or fifo");
+                    process::exit(1);
+                }
+            },
+            _ => remaining.push(arg)
+        }
+    }
+    set_cache_defaults(capacity, eviction);
+    remaining
+}
+
+/// How well a cache has worked so far
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub evictions: usize,
+    /// The most entries held at once
+    pub peak: usize
+}
+
+impl CacheStats {
+    /// The fraction of lookups which were found, or 0 if there haven't been any
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 { 0.0 } else { self.hits as f64 / lookups as f64 }
+    }
+}
+
+impl Display for CacheStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{} hits, {} misses ({:.1}% hit rate), peak {} entries, {} evictions",
+            self.hits, self.misses, self.hit_rate() * 100.0, self.peak, self.evictions)
+    }
+}
+
+/// Results which have already been calculated, so that a recursive solution only calculates each once
+///
+/// When bounded, a full cache forgets an entry (chosen by its `Eviction`) for each new one, which costs calculating it again if it is needed later.
+#[derive(Debug)]
+pub struct Cache<K, V> {
+    entries: HashMap<K, (V, u64)>,
+    // the key of each entry by when it was last used (or inserted, for FIFO), only kept when bounded
+    order: BTreeMap<u64, K>,
+    capacity: Option<usize>,
+    eviction: Eviction,
+    tick: u64,
+    stats: CacheStats
+}
+
+impl<K: Eq + Hash + Clone, V> Cache<K, V> {
+    /// A cache with the capacity and eviction given by --cache-capacity and --cache-eviction (unbounded by default)
+    pub fn new() -> Self {
+        let capacity = match CAPACITY.load(Ordering::Relaxed) {
+            usize::MAX => None,
+            capacity => Some(capacity)
+        };
+        let eviction = match EVICTION.load(Ordering::Relaxed) {
+            0 => Eviction::LeastRecentlyUsed,
+            _ => Eviction::FirstInFirstOut
+        };
+        Self::with_capacity(capacity, eviction)
+    }
+
+    pub fn unbounded() -> Self {
+        Self::with_capacity(None, Eviction::LeastRecentlyUsed)
+    }
+
+    pub fn bounded(capacity: usize, eviction: Eviction) -> Self {
+        Self::with_capacity(Some(capacity), eviction)
+    }
+
+    fn with_capacity(capacity: Option<usize>, eviction: Eviction) -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            capacity,
+            eviction,
+            tick: 0,
+            stats: CacheStats::default()
+        }
+    }
+
+    /// The value remembered for `key`, counting a hit or miss
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.tick += 1;
+        match self.entries.get_mut(key) {
+            Some((value, used)) => {
+                self.stats.hits += 1;
+                if self.capacity.is_some() && self.eviction == Eviction::LeastRecentlyUsed {
+                    touch(&mut self.order, used, self.tick);
+                }
+                Some(value)
+            },
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Remember the value for `key`, first forgetting another entry if the cache is full
+    ///
+    /// Replacing the value of a key counts as using it for LRU, but doesn't change when it was first inserted for FIFO.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.tick += 1;
+        if let Some((old, used)) = self.entries.get_mut(&key) {
+            *old = value;
+            if self.capacity.is_some() && self.eviction == Eviction::LeastRecentlyUsed {
+                touch(&mut self.order, used, self.tick);
+            }
+            return;
+        }
+        if let Some(capacity) = self.capacity {
+            if self.entries.len() >= capacity {
+                match self.order.pop_first() {
+                    Some((_, oldest)) => {
+                        self.entries.remove(&oldest);
+                        self.stats.evictions += 1;
+                    },
+                    None => return // no room at all
+                }
+            }
+            self.order.insert(self.tick, key.clone());
+        }
+        self.entries.insert(key, (value, self.tick));
+        self.stats.peak = self.stats.peak.max(self.entries.len());
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+}
+
+// move an entry last used at `used` to the end of the order
+fn touch<K>(order: &mut BTreeMap<u64, K>, used: &mut u64, tick: u64) {
+    let key = order.remove(used).unwrap();
+    *used = tick;
+    order.insert(tick, key);
+}
+
+impl<K: Eq + Hash + Clone, V> Default for Cache<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
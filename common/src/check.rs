@@ -0,0 +1,40 @@
+use std::process;
+use crate::ParseError;
+
+/// Every line which fails to parse, rather than stopping at the first
+pub fn check_lines<'a, T, F: Fn(&'a str) -> Result<T, ParseError>>(text: &'a str, parse: F) -> Vec<ParseError> {
+    text.lines()
+        .enumerate()
+        .filter_map(|(i, line)| parse(line).err().map(|e| e.with_line(i + 1)))
+        .collect()
+}
+
+/// Everything wrong with an input: the malformed lines, or if there aren't any, whatever else stops it parsing as a whole (eg. a missing start)
+pub fn check<T, F: FnOnce() -> Result<T, ParseError>>(lines: Vec<ParseError>, whole: F) -> Vec<ParseError> {
+    if lines.is_empty() {
+        whole().err().into_iter().collect()
+    } else {
+        lines
+    }
+}
+
+/// Remove a --check flag, which asks for the input to be validated rather than solved
+pub fn check_flag(args: Vec<String>) -> (bool, Vec<String>) {
+    let check = args.iter().any(|a| a == "--check");
+    (check, args.into_iter().filter(|a| a != "--check").collect())
+}
+
+/// Report each problem found by a check (or that there were none) and exit, with a non-zero status if there were any
+pub fn exit_check(file: &str, errors: Vec<ParseError>) -> ! {
+    for error in &errors {
+        println!("{}", error.clone().in_file(file));
+    }
+    let file = if file == crate::STDIN { "stdin" } else { file };
+    if errors.is_empty() {
+        println!("{}: OK", file);
+        process::exit(0);
+    } else {
+        println!("{}: {} problem(s)", file, errors.len());
+        process::exit(1);
+    }
+}
@@ -0,0 +1,119 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::process;
+use std::str::FromStr;
+use crate::STDIN;
+
+/// An error in puzzle input, pointing at where it went wrong and what was expected there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub expected: String,
+    pub found: String
+}
+
+impl ParseError {
+    pub fn new<E: Into<String>, F: Into<String>>(expected: E, found: F) -> Self {
+        Self {
+            file: None,
+            line: None,
+            column: None,
+            expected: expected.into(),
+            found: found.into()
+        }
+    }
+
+    /// An error for `token`, which should be a slice of `line` so that its column can be found
+    pub fn at<E: Into<String>>(line: &str, token: &str, expected: E) -> Self {
+        let mut error = Self::new(expected, token);
+        error.column = column_of(line, token);
+        error
+    }
+
+    /// Set the (1-based) line number, unless it is already known
+    pub fn with_line(mut self, line: usize) -> Self {
+        if self.line.is_none() {
+            self.line = Some(line);
+        }
+        self
+    }
+
+    /// Set the (1-based) column number, unless it is already known
+    pub fn with_column(mut self, column: usize) -> Self {
+        if self.column.is_none() {
+            self.column = Some(column);
+        }
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(if file == STDIN { "stdin" } else { file }.to_string());
+        self
+    }
+
+    /// Shift the line number, for errors in a section which doesn't start on the first line of the file
+    pub fn offset_lines(mut self, offset: usize) -> Self {
+        if let Some(line) = &mut self.line {
+            *line += offset;
+        }
+        self
+    }
+
+    /// Report this error (without a backtrace) and exit with a non-zero status
+    pub fn exit(self, file: &str) -> ! {
+        eprintln!("{}", self.in_file(file));
+        process::exit(1);
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let mut location = Vec::new();
+        if let Some(file) = &self.file {
+            location.push(file.clone());
+        }
+        if let Some(line) = self.line {
+            location.push(format!("line {}", line));
+        }
+        if let Some(column) = self.column {
+            location.push(format!("column {}", column));
+        }
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        if self.found.is_empty() {
+            write!(f, "expected {} but found nothing", self.expected)
+        } else {
+            write!(f, "expected {} but found {}", self.expected, self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The (1-based) column of `token` within `line`, if it is a slice of it
+fn column_of(line: &str, token: &str) -> Option<usize> {
+    let start = line.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).checked_sub(start)?;
+    if offset + token.len() <= line.len() {
+        Some(line[..offset].chars().count() + 1)
+    } else {
+        None
+    }
+}
+
+/// Parse each line of text, adding the line number to any error
+pub fn parse_lines<T: FromStr<Err = ParseError>>(text: &str) -> Result<Vec<T>, ParseError> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: ParseError| e.with_line(i + 1)))
+        .collect()
+}
+
+/// Parse a number from `token`, which should be a slice of `line` so that its column can be found
+pub fn parse_number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::at(line, token, "a number"))
+}
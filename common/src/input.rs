@@ -0,0 +1,42 @@
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::process;
+
+/// The filename which means the input should be read from stdin
+pub const STDIN: &str = "-";
+
+/// Read the whole input from a file, or from stdin if the filename is `-`
+pub fn read_input(filename: &str) -> io::Result<String> {
+    if filename == STDIN {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        fs::read_to_string(filename)
+    }
+}
+
+/// Open a file (or stdin if the filename is `-`) to be read a line at a time, for inputs too big to hold as text
+pub fn open_input(filename: &str) -> io::Result<Box<dyn BufRead>> {
+    if filename == STDIN {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(filename)?)))
+    }
+}
+
+/// Read the whole input like `read_input`, or report why it couldn't be read and exit with a non-zero status
+pub fn read_input_or_exit(filename: &str) -> String {
+    read_input(filename).unwrap_or_else(|e| exit_unreadable(filename, e))
+}
+
+/// Open the input like `open_input`, or report why it couldn't be opened and exit with a non-zero status
+pub fn open_input_or_exit(filename: &str) -> Box<dyn BufRead> {
+    open_input(filename).unwrap_or_else(|e| exit_unreadable(filename, e))
+}
+
+// a missing file is a mistake in the arguments rather than a bug, so it gets a message rather than a panic
+fn exit_unreadable(filename: &str, error: io::Error) -> ! {
+    eprintln!("Error reading from {}: {}", if filename == STDIN { "stdin" } else { filename }, error);
+    process::exit(1);
+}
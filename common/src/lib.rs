@@ -0,0 +1,13 @@
+mod cache;
+mod check;
+mod error;
+mod input;
+mod log;
+mod sections;
+
+pub use crate::cache::{Cache, CacheStats, Eviction, cache_flags, set_cache_defaults};
+pub use crate::check::{check, check_flag, check_lines, exit_check};
+pub use crate::error::{ParseError, parse_lines, parse_number};
+pub use crate::input::{STDIN, open_input, open_input_or_exit, read_input, read_input_or_exit};
+pub use crate::log::{Level, Progress, enabled, level, log, log_flags, set_level};
+pub use crate::sections::{Section, sections, split_sections};
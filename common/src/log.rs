@@ -0,0 +1,152 @@
+use std::fmt::Arguments;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{Duration, Instant};
+
+/// How much to report on stderr while solving (answers always go to stdout)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Quiet,
+    Normal,
+    Verbose,
+    Debug
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Normal as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Quiet,
+        1 => Level::Normal,
+        2 => Level::Verbose,
+        _ => Level::Debug
+    }
+}
+
+pub fn enabled(level: Level) -> bool {
+    self::level() >= level
+}
+
+/// Write a line to stderr, if the current level includes it (use the `verbose!` and `debug!` macros rather than calling this directly)
+pub fn log(level: Level, args: Arguments) {
+    if enabled(level) {
+        eprintln!("{}", args);
+    }
+}
+
+/// Set the level from (and remove) any --quiet or --verbose flags, where --verbose can be given twice for debug output
+pub fn log_flags(args: Vec<String>) -> Vec<String> {
+    let mut level = Level::Normal;
+    let mut remaining = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "-q" | "--quiet" => level = Level::Quiet,
+            "-v" | "--verbose" => level = if level == Level::Verbose { Level::Debug } else { Level::Verbose },
+            _ => remaining.push(arg)
+        }
+    }
+    set_level(level);
+    remaining
+}
+
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        $crate::log($crate::Level::Verbose, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        $crate::log($crate::Level::Debug, format_args!($($arg)*))
+    };
+}
+
+const BAR_WIDTH: usize = 30;
+const REDRAW_EVERY: Duration = Duration::from_millis(100);
+const LINE_EVERY: Duration = Duration::from_secs(5);
+
+/// Progress through a known number of steps, reported on stderr (with an estimate of the time remaining) when verbose
+///
+/// On a terminal this is a bar which is redrawn in place, otherwise it is a line every few seconds.
+pub struct Progress {
+    label: String,
+    total: usize,
+    done: usize,
+    start: Instant,
+    last_report: Option<Instant>,
+    enabled: bool,
+    bar: bool
+}
+
+impl Progress {
+    pub fn new(label: &str, total: usize) -> Self {
+        Self {
+            label: label.to_string(),
+            total,
+            done: 0,
+            start: Instant::now(),
+            last_report: None,
+            enabled: enabled(Level::Verbose),
+            bar: io::stderr().is_terminal()
+        }
+    }
+
+    pub fn step(&mut self) {
+        self.set(self.done + 1);
+    }
+
+    pub fn set(&mut self, done: usize) {
+        self.done = done;
+        if !self.enabled {
+            return;
+        }
+        let now = Instant::now();
+        let every = if self.bar { REDRAW_EVERY } else { LINE_EVERY };
+        if self.last_report.is_none_or(|last| now - last >= every) {
+            self.last_report = Some(now);
+            self.report();
+        }
+    }
+
+    /// The estimated time until all steps are done, assuming the remaining steps take as long as those so far
+    pub fn eta(&self) -> Option<Duration> {
+        if self.done == 0 {
+            None
+        } else {
+            let per_step = self.start.elapsed().as_secs_f64() / self.done as f64;
+            Some(Duration::from_secs_f64(per_step * self.total.saturating_sub(self.done) as f64))
+        }
+    }
+
+    fn report(&self) {
+        let fraction = if self.total == 0 { 1.0 } else { self.done as f64 / self.total as f64 };
+        let eta = match self.eta() {
+            Some(eta) => format!("ETA {}s", eta.as_secs()),
+            None => "ETA ?".to_string()
+        };
+        if self.bar {
+            let filled = ((fraction * BAR_WIDTH as f64) as usize).min(BAR_WIDTH);
+            eprint!("\r\x1b[K{} [{}{}] {}/{} ({:.1}%) {}", self.label, "#".repeat(filled), ".".repeat(BAR_WIDTH - filled), self.done, self.total, fraction * 100.0, eta);
+            let _ = io::stderr().flush();
+        } else {
+            eprintln!("{}: {}/{} ({:.1}%) {}", self.label, self.done, self.total, fraction * 100.0, eta);
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.enabled {
+            if self.bar {
+                eprint!("\r\x1b[K");
+            }
+            eprintln!("{}: {} steps took {:.1}s", self.label, self.done, self.start.elapsed().as_secs_f64());
+        }
+    }
+}
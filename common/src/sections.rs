@@ -0,0 +1,76 @@
+use std::str::FromStr;
+use crate::{ParseError, check_lines, parse_lines};
+
+/// A block of lines from the input, separated from other blocks by blank lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub text: &'a str,
+    /// The (1-based) line number of the first line of this section within the whole input
+    pub first_line: usize
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> {
+        self.text.lines()
+    }
+
+    /// Parse the whole section, with error line numbers relative to the whole input
+    pub fn parse<T: FromStr<Err = ParseError>>(&self) -> Result<T, ParseError> {
+        self.text.parse().map_err(|e| self.locate(e))
+    }
+
+    /// Parse each line of the section, with error line numbers relative to the whole input
+    pub fn parse_lines<T: FromStr<Err = ParseError>>(&self) -> Result<Vec<T>, ParseError> {
+        parse_lines(self.text).map_err(|e| self.locate(e))
+    }
+
+    /// Check each line of the section, with error line numbers relative to the whole input
+    pub fn check_lines<T, F: Fn(&'a str) -> Result<T, ParseError>>(&self, parse: F) -> Vec<ParseError> {
+        check_lines(self.text, parse).into_iter().map(|e| self.locate(e)).collect()
+    }
+
+    /// Move an error from within this section to its line in the whole input (or the start of the section, if its line isn't known)
+    pub fn locate(&self, error: ParseError) -> ParseError {
+        error.with_line(1).offset_lines(self.first_line - 1)
+    }
+}
+
+/// Split text into sections on blank lines, accepting LF or CRLF line endings and any number of blank lines (including leading or trailing)
+pub fn sections(text: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut current: Option<(usize, usize, usize)> = None; // start, end, first line
+    let mut offset = 0;
+    for (i, line) in text.split_inclusive('\n').enumerate() {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some((start, end, first_line)) = current.take() {
+                sections.push(Section {
+                    text: &text[start..end],
+                    first_line
+                });
+            }
+        } else if let Some((_, end, _)) = &mut current {
+            *end = offset + content.len();
+        } else {
+            current = Some((offset, offset + content.len(), i + 1));
+        }
+        offset += line.len();
+    }
+    if let Some((start, end, first_line)) = current {
+        sections.push(Section {
+            text: &text[start..end],
+            first_line
+        });
+    }
+    sections
+}
+
+/// Split text into exactly `count` sections, or fail describing what they should have been
+pub fn split_sections<'a>(text: &'a str, count: usize, expected: &str) -> Result<Vec<Section<'a>>, ParseError> {
+    let sections = sections(text);
+    if sections.len() == count {
+        Ok(sections)
+    } else {
+        Err(ParseError::new(expected, format!("{} sections", sections.len())))
+    }
+}
@@ -0,0 +1,74 @@
+use common::{Cache, Eviction};
+
+#[test]
+fn test_least_recently_used() {
+    let mut cache = Cache::bounded(2, Eviction::LeastRecentlyUsed);
+    cache.insert(1, "one");
+    cache.insert(2, "two");
+    assert_eq!(cache.get(&1), Some(&"one"));
+    cache.insert(3, "three"); // 2 is the least recently used
+    assert_eq!(cache.get(&2), None);
+    assert_eq!(cache.get(&1), Some(&"one"));
+    assert_eq!(cache.get(&3), Some(&"three"));
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses, stats.evictions, stats.peak), (3, 1, 1, 2));
+}
+
+#[test]
+fn test_first_in_first_out() {
+    let mut cache = Cache::bounded(2, Eviction::FirstInFirstOut);
+    cache.insert(1, "one");
+    cache.insert(2, "two");
+    assert_eq!(cache.get(&1), Some(&"one"));
+    cache.insert(3, "three"); // 1 is the oldest, even though it was just used
+    assert_eq!(cache.get(&1), None);
+    assert_eq!(cache.get(&2), Some(&"two"));
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn test_unbounded() {
+    let mut cache = Cache::unbounded();
+    for i in 0..1000 {
+        cache.insert(i, i * i);
+    }
+    cache.insert(7, 0); // replacing doesn't grow the cache
+    assert_eq!(cache.get(&999), Some(&998001));
+    assert_eq!(cache.get(&7), Some(&0));
+    assert_eq!(cache.stats().peak, 1000);
+    assert_eq!(cache.stats().evictions, 0);
+}
+
+#[test]
+fn test_no_capacity() {
+    let mut cache = Cache::bounded(0, Eviction::LeastRecentlyUsed);
+    cache.insert(1, 1);
+    assert!(cache.is_empty());
+    assert_eq!(cache.get(&1), None);
+}
+
+// the keys left after inserting 1, 2 and 3, replacing 1, then inserting 4 and 5
+fn kept_after_replacing(eviction: Eviction) -> Vec<i32> {
+    let mut cache = Cache::bounded(3, eviction);
+    for key in 1..=3 {
+        cache.insert(key, "first");
+    }
+    cache.insert(1, "again");
+    cache.insert(4, "first");
+    cache.insert(5, "first");
+    (1..=5).filter(|key| cache.get(key).is_some()).collect()
+}
+
+#[test]
+fn test_replacing() {
+    // replacing 1 makes it the most recently used, so 2 and 3 go first...
+    assert_eq!(kept_after_replacing(Eviction::LeastRecentlyUsed), vec![1, 4, 5]);
+    // ...but it was still inserted first, so it goes first (then 2)
+    assert_eq!(kept_after_replacing(Eviction::FirstInFirstOut), vec![3, 4, 5]);
+
+    let mut cache = Cache::bounded(2, Eviction::FirstInFirstOut);
+    cache.insert(1, "one");
+    cache.insert(1, "uno");
+    assert_eq!(cache.get(&1), Some(&"uno"));
+    assert_eq!((cache.len(), cache.stats().evictions), (1, 0));
+}
@@ -0,0 +1,42 @@
+use common::{ParseError, STDIN, parse_number};
+
+#[test]
+fn test_display() {
+    assert_eq!(ParseError::new("a number", "x").to_string(), "expected a number but found x");
+    assert_eq!(ParseError::new("a number", "").to_string(), "expected a number but found nothing");
+    assert_eq!(ParseError::new("a number", "x").with_line(3).to_string(), "line 3: expected a number but found x");
+    assert_eq!(ParseError::new("a number", "x").with_column(5).with_line(3).in_file("input.txt").to_string(), "input.txt, line 3, column 5: expected a number but found x");
+}
+
+#[test]
+fn test_column() {
+    let line = "12 ab 34";
+    assert_eq!(ParseError::at(line, &line[3..5], "a number").column, Some(4));
+    assert_eq!(ParseError::at(line, line, "a number").column, Some(1));
+    assert_eq!(parse_number::<u32>(line, &line[3..5]).unwrap_err().to_string(), "column 4: expected a number but found ab");
+    // columns count characters rather than bytes
+    let line = "é x";
+    assert_eq!(ParseError::at(line, &line[3..], "a number").column, Some(3));
+    // a token which isn't part of the line has no column
+    assert_eq!(ParseError::at(line, "x", "a number").column, None);
+    // nor does an explicit column replace one already known
+    assert_eq!(ParseError::at(line, &line[3..], "a number").with_column(9).column, Some(3));
+}
+
+#[test]
+fn test_lines() {
+    // the first line number set wins
+    assert_eq!(ParseError::new("x", "y").with_line(2).with_line(5).line, Some(2));
+    // offset_lines shifts a line which is already known...
+    assert_eq!(ParseError::new("x", "y").with_line(2).offset_lines(10).line, Some(12));
+    // ...but does nothing before there is one, so a later with_line isn't offset
+    assert_eq!(ParseError::new("x", "y").offset_lines(10).with_line(2).line, Some(2));
+}
+
+#[test]
+fn test_in_file() {
+    assert_eq!(ParseError::new("x", "y").in_file("day1/input.txt").file.as_deref(), Some("day1/input.txt"));
+    assert_eq!(ParseError::new("x", "y").in_file(STDIN).to_string(), "stdin: expected x but found y");
+    // unlike the line and column, the file is always replaced
+    assert_eq!(ParseError::new("x", "y").in_file("a.txt").in_file("b.txt").file.as_deref(), Some("b.txt"));
+}
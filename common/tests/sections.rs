@@ -0,0 +1,47 @@
+use common::{ParseError, Section, sections, split_sections};
+
+fn texts<'a>(sections: &[Section<'a>]) -> Vec<(&'a str, usize)> {
+    sections.iter().map(|s| (s.text, s.first_line)).collect()
+}
+
+#[test]
+fn test_lf() {
+    let text = "a\nb\n\nc\n";
+    assert_eq!(texts(&sections(text)), vec![("a\nb", 1), ("c", 4)]);
+}
+
+#[test]
+fn test_crlf() {
+    let text = "a\r\nb\r\n\r\nc\r\n";
+    // the line endings within a section are kept, but not the one at its end
+    assert_eq!(texts(&sections(text)), vec![("a\r\nb", 1), ("c", 4)]);
+    assert_eq!(sections(text)[0].lines().collect::<Vec<_>>(), vec!["a", "b"]);
+}
+
+#[test]
+fn test_blank_lines() {
+    // leading, trailing and several blank lines in a row (one only spaces) don't make empty sections
+    let text = "\na\n\n  \n\nb\nc\n\n";
+    assert_eq!(texts(&sections(text)), vec![("a", 2), ("b\nc", 6)]);
+    assert_eq!(texts(&sections("a\n\n")), vec![("a", 1)]);
+    assert!(sections("\n\n").is_empty());
+}
+
+#[test]
+fn test_split_sections() {
+    let text = "a\n\nb\n\n";
+    assert_eq!(texts(&split_sections(text, 2, "2 sections").unwrap()), vec![("a", 1), ("b", 3)]);
+    let error = split_sections(text, 3, "3 sections").unwrap_err();
+    assert_eq!((error.expected.as_str(), error.found.as_str()), ("3 sections", "2 sections"));
+}
+
+#[test]
+fn test_locate() {
+    let section = sections("a\n\nb\nc\nd\n")[1];
+    assert_eq!(section.first_line, 3);
+    // a line within the section moves to its line in the whole input, or the first line of the section if it isn't known
+    assert_eq!(section.locate(ParseError::new("x", "y").with_line(2)).line, Some(4));
+    assert_eq!(section.locate(ParseError::new("x", "y")).line, Some(3));
+    let errors = section.check_lines(|line| if line == "c" { Err(ParseError::new("not c", line)) } else { Ok(()) });
+    assert_eq!(errors.iter().map(|e| e.line).collect::<Vec<_>>(), vec![Some(4)]);
+}
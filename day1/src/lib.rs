@@ -0,0 +1,315 @@
+//! Day 1: Historian Hysteria
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+use common::{ParseError, parse_number};
+
+pub use crate::metric::{Metric, Score, metric, metrics};
+pub use crate::report::{Frequency, Pair, Report};
+
+mod metric;
+mod report;
+
+/// Lists of location ids side by side, one per column (two in the puzzle, but reconciling more works the same way)
+#[derive(Debug, Clone, Default)]
+pub struct Lists {
+    columns: Vec<Vec<i128>>
+}
+
+/// How the numbers on each line are separated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Any run of spaces or tabs
+    Whitespace,
+    /// Commas (CSV), with or without spaces around each number
+    Comma,
+    /// Tabs (TSV), with or without spaces around each number
+    Tab
+}
+
+impl Delimiter {
+    /// Tabs if the line has any, otherwise commas if it has any, otherwise whitespace
+    pub fn detect(line: &str) -> Self {
+        if line.contains('\t') {
+            Self::Tab
+        } else if line.contains(',') {
+            Self::Comma
+        } else {
+            Self::Whitespace
+        }
+    }
+
+    fn split<'a>(&self, line: &'a str) -> Vec<&'a str> {
+        match self {
+            Self::Whitespace => line.split_whitespace().collect(),
+            Self::Comma => line.split(',').map(str::trim).collect(),
+            Self::Tab => line.split('\t').map(str::trim).collect()
+        }
+    }
+
+    fn describe(&self) -> &'static str {
+        match self {
+            Self::Whitespace => "spaces",
+            Self::Comma => "commas",
+            Self::Tab => "tabs"
+        }
+    }
+}
+
+/// How a file of lists is laid out
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Format {
+    /// How the numbers are separated, or None to detect it from the first row which parses
+    pub delimiter: Option<Delimiter>,
+    /// Whether the first line is a header (eg. the name of each list) rather than numbers
+    pub header: bool
+}
+
+impl Format {
+    pub fn parse(&self, text: &str) -> Result<Lists, ParseError> {
+        let mut parser = Parser::new(*self);
+        for (i, line) in text.lines().enumerate() {
+            parser.add(i + 1, line)?;
+        }
+        Ok(parser.lists)
+    }
+
+    /// Parse the lists a line at a time, so that only the numbers (not the whole text) are held in memory
+    pub fn read<R: BufRead>(&self, mut reader: R) -> Result<Lists, ParseError> {
+        let mut parser = Parser::new(*self);
+        // one buffer for every line, rather than a new string each
+        let mut line = String::new();
+        for i in 1.. {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) => break,
+                Ok(_) => parser.add(i, line.trim_end_matches(['\n', '\r']))?,
+                Err(e) => return Err(ParseError::new("a line of text", e.to_string()).with_line(i))
+            }
+        }
+        Ok(parser.lists)
+    }
+
+    /// Every line which isn't 2 or more numbers, or has a different number of them than the first row
+    pub fn check(&self, text: &str) -> Vec<ParseError> {
+        let mut parser = Parser::new(*self);
+        text.lines().enumerate().filter_map(|(i, line)| parser.add(i + 1, line).err()).collect()
+    }
+}
+
+// the lists so far, and the format of the lines still to come
+struct Parser {
+    format: Format,
+    lists: Lists
+}
+
+impl Parser {
+    fn new(format: Format) -> Self {
+        Self {
+            format,
+            lists: Lists::default()
+        }
+    }
+
+    /// Add a row (line `number`, from 1), where the first row which parses decides the delimiter if it isn't known and the number of columns
+    fn add(&mut self, number: usize, line: &str) -> Result<(), ParseError> {
+        if number == 1 && self.format.header {
+            return Ok(());
+        }
+        let delimiter = self.format.delimiter.unwrap_or_else(|| Delimiter::detect(line));
+        let numbers = delimiter.split(line);
+        if numbers.len() < 2 {
+            return Err(ParseError::new(format!("2 or more numbers separated by {}", delimiter.describe()), line).with_line(number));
+        }
+        let row = numbers.iter().map(|n| parse_number(line, n)).collect::<Result<Vec<i128>, _>>().map_err(|e| e.with_line(number))?;
+        // not fixed by a bad row, which would make every good row after it an error too
+        self.format.delimiter = Some(delimiter);
+        let columns = &mut self.lists.columns;
+        if columns.is_empty() {
+            *columns = vec![Vec::new(); row.len()];
+        } else if row.len() != columns.len() {
+            return Err(ParseError::new(format!("{} numbers, like the first row", columns.len()), line).with_line(number));
+        }
+        for (column, n) in columns.iter_mut().zip(row) {
+            column.push(n);
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Lists {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Format::default().parse(text)
+    }
+}
+
+impl Lists {
+    /// Parse the lists a line at a time, detecting how they are separated
+    pub fn read<R: BufRead>(reader: R) -> Result<Self, ParseError> {
+        Format::default().read(reader)
+    }
+
+    /// How many lists there are side by side
+    pub fn columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// The numbers in list `i`, or none if there isn't one
+    pub fn column(&self, i: usize) -> &[i128] {
+        self.columns.get(i).map_or(&[], |c| c)
+    }
+
+    /// Every list sorted smallest first, so that the nth smallest of each are paired up
+    pub fn sorted(mut self) -> Self {
+        for column in &mut self.columns {
+            column.sort_unstable();
+        }
+        self
+    }
+
+    /// The total distance between each pair from lists `i` and `j`
+    pub fn error(&self, i: usize, j: usize) -> u128 {
+        self.column(i).iter().zip(self.column(j)).fold(0, |error, (a, b)| error.saturating_add(a.abs_diff(*b)))
+    }
+
+    /// Each number in list `i` multiplied by how often it appears in list `j`, summed
+    pub fn similarity(&self, i: usize, j: usize) -> i128 {
+        let mut counts: HashMap<i128, i128> = HashMap::new();
+        for b in self.column(j) {
+            *counts.entry(*b).or_default() += 1;
+        }
+        self.column(i).iter().fold(0, |similarity, a| similarity.saturating_add(a.saturating_mul(*counts.get(a).unwrap_or(&0))))
+    }
+
+    /// The error and similarity of lists `i` and `j` together, in a single pass over lists which must already be sorted
+    pub fn error_and_similarity(&self, i: usize, j: usize) -> (u128, i128) {
+        compare(self.column(i), self.column(j))
+    }
+
+    /// The error and similarity between every pair of (already sorted) lists
+    pub fn matrix(&self) -> (Matrix<u128>, Matrix<i128>) {
+        let n = self.columns();
+        let mut error = Matrix::new(n);
+        let mut similarity = Matrix::new(n);
+        for i in 0..n {
+            // both are symmetric, so each pair is only compared once
+            for j in i..n {
+                let (e, s) = self.error_and_similarity(i, j);
+                error.set(i, j, e);
+                similarity.set(i, j, s);
+            }
+        }
+        (error, similarity)
+    }
+
+    /// A matrix of each metric between every pair of lists, which should be as given (the metrics which compare them sorted are given them sorted)
+    pub fn scores(&self, metrics: &[Box<dyn Metric>]) -> Vec<Matrix<Score>> {
+        let sorted = if metrics.iter().any(|m| m.sorted()) { Some(self.clone().sorted()) } else { None };
+        // at least the two lists the puzzle compares, even if there are none
+        let n = self.columns().max(2);
+        metrics.iter().map(|metric| {
+            let lists = if metric.sorted() { sorted.as_ref().unwrap() } else { self };
+            let mut scores = Matrix::new(n);
+            for i in 0..n {
+                // every metric is symmetric, so each pair is only compared once, and each list with itself only when there is a matrix to show
+                let first = if n > 2 { i } else { i + 1 };
+                for j in first..n {
+                    scores.set(i, j, metric.measure(lists.column(i), lists.column(j)));
+                }
+            }
+            scores
+        }).collect()
+    }
+}
+
+// the totals saturate rather than overflow, which only numbers near the limits of an i128 could reach
+pub(crate) fn compare(a: &[i128], b: &[i128]) -> (u128, i128) {
+    let mut error: u128 = 0;
+    let mut similarity: i128 = 0;
+    // the start of the run of the current number in b, and how long the run is
+    let mut start = 0;
+    let mut run = 0;
+    for (i, n) in a.iter().enumerate() {
+        error = error.saturating_add(n.abs_diff(b[i]));
+        if i == 0 || a[i - 1] != *n {
+            // b is sorted too, so the run for n can only be at or after the last one
+            start += run;
+            while start < b.len() && b[start] < *n {
+                start += 1;
+            }
+            run = b[start..].iter().take_while(|m| *m == n).count();
+        }
+        similarity = similarity.saturating_add(n.saturating_mul(run as i128));
+    }
+    (error, similarity)
+}
+
+/// A number for every pair of lists, shown as a table with the lists numbered from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T> {
+    pub values: Vec<Vec<T>>
+}
+
+impl<T: Clone + Default> Matrix<T> {
+    fn new(n: usize) -> Self {
+        Self {
+            values: vec![vec![T::default(); n]; n]
+        }
+    }
+
+    fn set(&mut self, i: usize, j: usize, value: T) {
+        self.values[i][j] = value.clone();
+        self.values[j][i] = value;
+    }
+}
+
+impl<T: Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let n = self.values.len();
+        let mut rows = vec![std::iter::once(String::new()).chain((1..=n).map(|j| j.to_string())).collect()];
+        for (i, row) in self.values.iter().enumerate() {
+            rows.push(std::iter::once((i + 1).to_string()).chain(row.iter().map(|v| v.to_string())).collect());
+        }
+        write!(f, "{}", table(&rows))
+    }
+}
+
+// the cells lined up on the right in columns two spaces apart
+pub(crate) fn table(rows: &[Vec<String>]) -> String {
+    let mut widths = Vec::new();
+    for row in rows {
+        widths.resize(widths.len().max(row.len()), 0);
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let lines: Vec<String> = rows.iter().map(|row| {
+        row.iter().zip(&widths).map(|(cell, width)| format!("{:>1$}", cell, width)).collect::<Vec<_>>().join("  ")
+    }).collect();
+    lines.join("\n")
+}
+
+pub fn parse(input: &str) -> Result<Lists, ParseError> {
+    input.parse()
+}
+
+/// Every line which isn't 2 or more numbers, or has a different number of them than the first row
+pub fn check(input: &str) -> Vec<ParseError> {
+    Format::default().check(input)
+}
+
+/// The total distance between the first two sorted lists
+pub fn part1(input: &str) -> Result<u128, ParseError> {
+    let lists = parse(input)?;
+    Ok(lists.sorted().error(0, 1))
+}
+
+/// The similarity score of the first two lists
+pub fn part2(input: &str) -> Result<i128, ParseError> {
+    let lists = parse(input)?;
+    Ok(lists.similarity(0, 1))
+}
@@ -1,50 +1,94 @@
-use std::fs;
 use std::env;
-use std::str::FromStr;
+use std::process;
+use common::{check_flag, exit_check, log_flags, open_input_or_exit, read_input_or_exit, STDIN};
+use day1::{Format, Metric, metric, metrics};
 
-struct TwoLists {
-    a: Vec<usize>,
-    b: Vec<usize>
+fn main() {
+    let (check, args) = check_flag(log_flags(env::args().collect()));
+    let (chosen, args) = metric_flags(args);
+    let (report, args) = report_flag(args);
+    let header = args.iter().any(|a| a == "--header");
+    let args: Vec<String> = args.into_iter().filter(|a| a != "--header").collect();
+    let format = Format {
+        delimiter: None,
+        header
+    };
+    if args.len() <= 2 {
+        let filename = args.get(1).map_or(STDIN, |s| s.as_str());
+        if check {
+            let text = read_input_or_exit(filename);
+            exit_check(filename, format.check(&text));
+        }
+        // the lists can be tens of millions of lines, so they are read a line at a time and compared in one pass
+        let reader = open_input_or_exit(filename);
+        let lists = format.read(reader).unwrap_or_else(|e| e.exit(filename));
+        if let Some(top) = report {
+            let lists = lists.sorted();
+            // every pair of lists, though usually there are only the two
+            let n = lists.columns();
+            let reports: Vec<String> = (0..n).flat_map(|i| ((i + 1)..n).map(move |j| (i, j)))
+                .map(|(i, j)| lists.report(i, j, top).to_string())
+                .collect();
+            println!("{}", reports.join("\n\n"));
+        } else if !chosen.is_empty() {
+            for (metric, scores) in chosen.iter().zip(lists.scores(&chosen)) {
+                if lists.columns() > 2 {
+                    println!("{}:\n{}", metric.title(), scores);
+                } else {
+                    println!("{}: {}", metric.title(), scores.values[0][1]);
+                }
+            }
+        } else if lists.columns() > 2 {
+            let (error, similarity) = lists.sorted().matrix();
+            println!("Error:\n{}", error);
+            println!("Similarity:\n{}", similarity);
+        } else {
+            let (error, similarity) = lists.sorted().error_and_similarity(0, 1);
+            println!("Error: {}", error);
+            println!("Similarity: {}", similarity);
+        }
+    } else {
+        println!("Please provide 1 argument: Filename (or - for stdin, the default), with --header if the first line names the lists, --check to only validate it, --report K to list the K pairs which add most to the error, the numbers only in one list and the K numbers whose counts differ most between the lists, or --metric NAME (any number of times) to compare the lists by {} instead of the error and similarity",
+            metric_names());
+    }
 }
 
-impl FromStr for TwoLists {
-    type Err = String;
-
-    fn from_str(text: &str) -> Result<Self, Self::Err> {
-        let mut a = Vec::new();
-        let mut b = Vec::new();
-        for line in text.lines() {
-            let numbers: Vec<&str> = line.split("   ").collect();
-            if numbers.len() != 2 {
-                panic!("Line not 2 numbers: {}", line);
-            }
-            a.push(numbers[0].parse().unwrap());
-            b.push(numbers[1].parse().unwrap());
+// the metrics chosen by (and removed from) any --metric NAME arguments
+fn metric_flags(args: Vec<String>) -> (Vec<Box<dyn Metric>>, Vec<String>) {
+    let mut chosen = Vec::new();
+    let mut remaining = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--metric" {
+            chosen.push(args.next().and_then(|name| metric(&name)).unwrap_or_else(|| {
+                eprintln!("--metric should be followed by one of {}", metric_names());
+                process::exit(1);
+            }));
+        } else {
+            remaining.push(arg);
         }
-        Ok(Self {
-            a,b
-        })
     }
+    (chosen, remaining)
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let mut lists: TwoLists = text.parse().unwrap();
-        lists.a.sort();
-        lists.b.sort();
-        let mut error = 0;
-        let mut similarity = 0;
-        for i in 0..lists.a.len() {
-            error += lists.a[i].abs_diff(lists.b[i]);
-            similarity += lists.a[i] * lists.b.iter().filter(|n| **n == lists.a[i]).count();
+fn metric_names() -> String {
+    metrics().iter().map(|m| m.name()).collect::<Vec<_>>().join(", ")
+}
+
+// the number of pairs to report, from (and removed from) any --report K argument
+fn report_flag(args: Vec<String>) -> (Option<usize>, Vec<String>) {
+    let mut top = None;
+    let mut remaining = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--report" {
+            top = Some(args.next().and_then(|k| k.parse().ok()).unwrap_or_else(|| {
+                eprintln!("--report should be followed by the number of pairs to list");
+                process::exit(1);
+            }));
+        } else {
+            remaining.push(arg);
         }
-        println!("Error: {}", error);
-        println!("Similarity: {}", similarity);
-    } else {
-        println!("Please provide 1 argument: Filename");
     }
+    (top, remaining)
 }
\ No newline at end of file
@@ -0,0 +1,278 @@
+use std::fmt::{Display, Formatter};
+use crate::compare;
+
+/// What a metric measures, either a total (exact, unless it reaches the limits of an i128) or a ratio
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Score {
+    Total(i128),
+    Ratio(f64)
+}
+
+impl Default for Score {
+    fn default() -> Self {
+        Self::Total(0)
+    }
+}
+
+impl Display for Score {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::Total(total) => write!(f, "{}", total),
+            Self::Ratio(ratio) => write!(f, "{:.4}", ratio)
+        }
+    }
+}
+
+/// A way of comparing two lists of the same length
+pub trait Metric {
+    /// The name it is chosen by with --metric
+    fn name(&self) -> &'static str;
+
+    /// The name it is shown with, as a heading
+    fn title(&self) -> &'static str;
+
+    /// Whether it compares the lists sorted (the nth smallest of each), rather than each row as given
+    fn sorted(&self) -> bool {
+        true
+    }
+
+    fn measure(&self, a: &[i128], b: &[i128]) -> Score;
+}
+
+/// Every metric, in the order they are listed
+pub fn metrics() -> Vec<Box<dyn Metric>> {
+    vec![Box::new(Error), Box::new(Similarity), Box::new(SquaredError), Box::new(MaxDeviation),
+        Box::new(Spearman), Box::new(Kendall), Box::new(Jaccard)]
+}
+
+/// The metric with this name
+pub fn metric(name: &str) -> Option<Box<dyn Metric>> {
+    metrics().into_iter().find(|m| m.name() == name)
+}
+
+fn total(distance: u128) -> Score {
+    Score::Total(distance.try_into().unwrap_or(i128::MAX))
+}
+
+/// The total distance between each pair (part 1)
+pub struct Error;
+
+impl Metric for Error {
+    fn name(&self) -> &'static str {
+        "error"
+    }
+
+    fn title(&self) -> &'static str {
+        "Error"
+    }
+
+    fn measure(&self, a: &[i128], b: &[i128]) -> Score {
+        total(a.iter().zip(b).fold(0, |error, (a, b)| error.saturating_add(a.abs_diff(*b))))
+    }
+}
+
+/// Each number in one list multiplied by how often it appears in the other, summed (part 2)
+pub struct Similarity;
+
+impl Metric for Similarity {
+    fn name(&self) -> &'static str {
+        "similarity"
+    }
+
+    fn title(&self) -> &'static str {
+        "Similarity"
+    }
+
+    fn measure(&self, a: &[i128], b: &[i128]) -> Score {
+        Score::Total(compare(a, b).1)
+    }
+}
+
+/// The total of the square of the distance between each pair, which makes a few large distances count for more than many small ones
+pub struct SquaredError;
+
+impl Metric for SquaredError {
+    fn name(&self) -> &'static str {
+        "squared"
+    }
+
+    fn title(&self) -> &'static str {
+        "Squared error"
+    }
+
+    fn measure(&self, a: &[i128], b: &[i128]) -> Score {
+        total(a.iter().zip(b).fold(0, |error, (a, b)| error.saturating_add(a.abs_diff(*b).saturating_pow(2))))
+    }
+}
+
+/// The largest distance between any pair
+pub struct MaxDeviation;
+
+impl Metric for MaxDeviation {
+    fn name(&self) -> &'static str {
+        "max"
+    }
+
+    fn title(&self) -> &'static str {
+        "Maximum deviation"
+    }
+
+    fn measure(&self, a: &[i128], b: &[i128]) -> Score {
+        total(a.iter().zip(b).map(|(a, b)| a.abs_diff(*b)).max().unwrap_or(0))
+    }
+}
+
+/// How closely the rank of each number in its list follows the rank of the other number in its row, from -1 to 1
+pub struct Spearman;
+
+impl Metric for Spearman {
+    fn name(&self) -> &'static str {
+        "spearman"
+    }
+
+    fn title(&self) -> &'static str {
+        "Spearman correlation"
+    }
+
+    fn sorted(&self) -> bool {
+        false
+    }
+
+    fn measure(&self, a: &[i128], b: &[i128]) -> Score {
+        let (a, b) = (ranks(a), ranks(b));
+        let n = a.len() as f64;
+        let (mean_a, mean_b) = (a.iter().sum::<f64>() / n, b.iter().sum::<f64>() / n);
+        let mut covariance = 0.0;
+        let mut variance_a = 0.0;
+        let mut variance_b = 0.0;
+        for (x, y) in a.iter().zip(&b) {
+            covariance += (x - mean_a) * (y - mean_b);
+            variance_a += (x - mean_a).powi(2);
+            variance_b += (y - mean_b).powi(2);
+        }
+        // NaN if either list is all the same number, which has no ranking to follow
+        Score::Ratio(covariance / (variance_a * variance_b).sqrt())
+    }
+}
+
+// the rank of each number in its list from 1, where equal numbers share the average of their ranks
+fn ranks(values: &[i128]) -> Vec<f64> {
+    let mut order: Vec<(i128, usize)> = values.iter().copied().zip(0..).collect();
+    order.sort_unstable();
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    for run in order.chunk_by(|x, y| x.0 == y.0) {
+        let rank = (2 * start + run.len() + 1) as f64 / 2.0;
+        for (_, i) in run {
+            ranks[*i] = rank;
+        }
+        start += run.len();
+    }
+    ranks
+}
+
+/// How many pairs of rows are in the same order in both lists, less those in the opposite order, from -1 to 1 (tau-b, which allows for ties)
+pub struct Kendall;
+
+impl Metric for Kendall {
+    fn name(&self) -> &'static str {
+        "kendall"
+    }
+
+    fn title(&self) -> &'static str {
+        "Kendall correlation"
+    }
+
+    fn sorted(&self) -> bool {
+        false
+    }
+
+    fn measure(&self, a: &[i128], b: &[i128]) -> Score {
+        // Knight's method: sorted by a then b, the pairs in the opposite order are the swaps needed to sort by b
+        let mut rows: Vec<(i128, i128)> = a.iter().copied().zip(b.iter().copied()).collect();
+        rows.sort_unstable();
+        let pairs = pairs(rows.len());
+        let tied_a = tied(&rows, |r| r.0);
+        let tied_both = tied(&rows, |r| *r);
+        let mut b: Vec<i128> = rows.iter().map(|r| r.1).collect();
+        let discordant = swaps(&mut b);
+        let tied_b = tied(&b, |n| *n);
+        let difference = pairs as f64 - tied_a as f64 - tied_b as f64 + tied_both as f64 - 2.0 * discordant as f64;
+        Score::Ratio(difference / ((pairs - tied_a) as f64 * (pairs - tied_b) as f64).sqrt())
+    }
+}
+
+fn pairs(n: usize) -> u128 {
+    let n = n as u128;
+    n * n.saturating_sub(1) / 2
+}
+
+// the pairs of neighbouring items (in a sorted slice) which have the same key
+fn tied<T, K: PartialEq, F: Fn(&T) -> K>(sorted: &[T], key: F) -> u128 {
+    sorted.chunk_by(|x, y| key(x) == key(y)).map(|run| pairs(run.len())).sum()
+}
+
+// sort by merging, counting how many pairs were out of order
+fn swaps(values: &mut [i128]) -> u128 {
+    if values.len() < 2 {
+        return 0;
+    }
+    let middle = values.len() / 2;
+    let mut swaps = swaps(&mut values[..middle]) + swaps(&mut values[middle..]);
+    let mut merged = Vec::with_capacity(values.len());
+    let (mut i, mut j) = (0, middle);
+    while i < middle && j < values.len() {
+        if values[j] < values[i] {
+            // it goes before everything left in the first half
+            swaps += (middle - i) as u128;
+            merged.push(values[j]);
+            j += 1;
+        } else {
+            merged.push(values[i]);
+            i += 1;
+        }
+    }
+    merged.extend_from_slice(&values[i..middle]);
+    merged.extend_from_slice(&values[j..]);
+    values.copy_from_slice(&merged);
+    swaps
+}
+
+/// How many different numbers the lists have in common, as a fraction of the different numbers in either
+pub struct Jaccard;
+
+impl Metric for Jaccard {
+    fn name(&self) -> &'static str {
+        "jaccard"
+    }
+
+    fn title(&self) -> &'static str {
+        "Jaccard overlap"
+    }
+
+    fn measure(&self, a: &[i128], b: &[i128]) -> Score {
+        let mut a = a.iter().peekable();
+        let mut b = b.iter().peekable();
+        let mut both = 0;
+        let mut either = 0;
+        // both are sorted, so the sets are merged like a sorted merge which skips repeats
+        loop {
+            let next = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) => {
+                    if x == y {
+                        both += 1;
+                    }
+                    **x.min(y)
+                },
+                (Some(x), None) => **x,
+                (None, Some(y)) => **y,
+                (None, None) => break
+            };
+            either += 1;
+            while a.next_if(|n| **n == next).is_some() {}
+            while b.next_if(|n| **n == next).is_some() {}
+        }
+        // two empty lists are the same set
+        Score::Ratio(if either == 0 { 1.0 } else { both as f64 / either as f64 })
+    }
+}
@@ -0,0 +1,141 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::{Display, Formatter};
+use crate::{Lists, table};
+
+/// The nth smallest number of each list, which are paired up by sorting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Pair {
+    /// From 1 for the smallest of each
+    pub rank: usize,
+    pub a: i128,
+    pub b: i128
+}
+
+impl Pair {
+    pub fn distance(&self) -> u128 {
+        self.a.abs_diff(self.b)
+    }
+}
+
+/// How many times a number appears in each list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frequency {
+    pub number: i128,
+    pub a: usize,
+    pub b: usize
+}
+
+impl Frequency {
+    pub fn difference(&self) -> usize {
+        self.a.abs_diff(self.b)
+    }
+}
+
+/// Where two sorted lists differ: the pairs which add most to the error, and the numbers each has which the other doesn't
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// Which two lists, from 0
+    pub lists: (usize, usize),
+    /// The pairs furthest apart, furthest first (then by rank)
+    pub divergent: Vec<Pair>,
+    /// Numbers which are in the first list but never the second, with how many times each appears, smallest first
+    pub only_in_a: Vec<(i128, usize)>,
+    /// Numbers which are in the second list but never the first, with how many times each appears, smallest first
+    pub only_in_b: Vec<(i128, usize)>,
+    /// The numbers whose counts differ most between the lists, most different first (then smallest first)
+    pub frequencies: Vec<Frequency>,
+    /// How many numbers have different counts in each list, of which `frequencies` are the top few
+    pub differing: usize
+}
+
+impl Lists {
+    /// The report on lists `i` and `j` (which must already be sorted), with the `top` pairs furthest apart and numbers whose counts differ most
+    pub fn report(&self, i: usize, j: usize, top: usize) -> Report {
+        let (a, b) = (self.column(i), self.column(j));
+        // only the furthest so far are kept, nearest on top of the heap to be replaced first
+        // (there can't be more of them than pairs, however big `top` is)
+        let mut furthest = BinaryHeap::with_capacity(top.min(a.len().min(b.len())) + 1);
+        for (rank, (a, b)) in a.iter().zip(b).enumerate() {
+            let pair = Pair {
+                rank: rank + 1,
+                a: *a,
+                b: *b
+            };
+            furthest.push(Reverse((pair.distance(), Reverse(pair))));
+            if furthest.len() > top {
+                furthest.pop();
+            }
+        }
+        let divergent = furthest.into_sorted_vec().into_iter().map(|Reverse((_, Reverse(pair)))| pair).collect();
+        let mut report = Report {
+            lists: (i, j),
+            divergent,
+            only_in_a: Vec::new(),
+            only_in_b: Vec::new(),
+            frequencies: Vec::new(),
+            differing: 0
+        };
+        // like the pairs, only the most different counts so far are kept
+        let mut most = BinaryHeap::with_capacity(top.min(a.len() + b.len()) + 1);
+        for frequency in frequencies(a, b) {
+            if frequency.a == frequency.b {
+                continue;
+            }
+            report.differing += 1;
+            if frequency.b == 0 {
+                report.only_in_a.push((frequency.number, frequency.a));
+            } else if frequency.a == 0 {
+                report.only_in_b.push((frequency.number, frequency.b));
+            }
+            most.push(Reverse((frequency.difference(), Reverse(frequency.number), frequency.a, frequency.b)));
+            if most.len() > top {
+                most.pop();
+            }
+        }
+        report.frequencies = most.into_sorted_vec().into_iter().map(|Reverse((_, Reverse(number), a, b))| Frequency {
+            number,
+            a,
+            b
+        }).collect();
+        report
+    }
+}
+
+// merge the runs of each number in both sorted lists
+fn frequencies<'a>(a: &'a [i128], b: &'a [i128]) -> impl Iterator<Item = Frequency> + 'a {
+    let mut a = a.chunk_by(|x, y| x == y).peekable();
+    let mut b = b.chunk_by(|x, y| x == y).peekable();
+    std::iter::from_fn(move || {
+        let number = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) => x[0].min(y[0]),
+            (Some(x), None) => x[0],
+            (None, Some(y)) => y[0],
+            (None, None) => return None
+        };
+        Some(Frequency {
+            number,
+            a: a.next_if(|run| run[0] == number).map_or(0, |run| run.len()),
+            b: b.next_if(|run| run[0] == number).map_or(0, |run| run.len())
+        })
+    })
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let (a, b) = (format!("List {}", self.lists.0 + 1), format!("List {}", self.lists.1 + 1));
+        let mut rows = vec![vec!["Rank".to_string(), a.clone(), b.clone(), "Distance".to_string()]];
+        rows.extend(self.divergent.iter().map(|p| vec![p.rank.to_string(), p.a.to_string(), p.b.to_string(), p.distance().to_string()]));
+        writeln!(f, "Most divergent pairs:\n{}", table(&rows))?;
+        let counted = |numbers: &[(i128, usize)]| if numbers.is_empty() {
+            "none".to_string()
+        } else {
+            numbers.iter().map(|(n, count)| if *count == 1 { n.to_string() } else { format!("{} (x{})", n, count) }).collect::<Vec<_>>().join(", ")
+        };
+        writeln!(f, "Only in {}: {}", a.to_lowercase(), counted(&self.only_in_a))?;
+        writeln!(f, "Only in {}: {}", b.to_lowercase(), counted(&self.only_in_b))?;
+        let mut rows = vec![vec!["Number".to_string(), a, b, "Difference".to_string()]];
+        rows.extend(self.frequencies.iter().map(|f| vec![f.number.to_string(), f.a.to_string(), f.b.to_string(), f.difference().to_string()]));
+        write!(f, "Frequencies (the {} of {} numbers whose counts differ most):\n{}", self.frequencies.len(), self.differing, table(&rows))
+    }
+}
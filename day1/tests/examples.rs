@@ -0,0 +1,86 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day1::part1(input).unwrap(), 11);
+    assert_eq!(day1::part2(input).unwrap(), 31);
+}
+
+#[test]
+fn test_check() {
+    assert!(day1::check(include_str!("../test.txt")).is_empty());
+    let errors = day1::check("3   4\n4,3\n2   x\n1   3\n");
+    assert_eq!(errors.iter().map(|e| e.line).collect::<Vec<_>>(), vec![Some(2), Some(3)]);
+}
+
+#[test]
+fn test_read() {
+    let input = include_str!("../test.txt");
+    let lists = day1::Lists::read(input.as_bytes()).unwrap().sorted();
+    assert_eq!(lists.error_and_similarity(0, 1), (11, 31));
+    let error = day1::Lists::read("3   4\n4   x\n".as_bytes()).unwrap_err();
+    assert_eq!(error.line, Some(2));
+}
+
+#[test]
+fn test_matrix() {
+    let lists = day1::parse("3   4   3\n4   3   3\n2   5   1\n").unwrap().sorted();
+    let (error, similarity) = lists.matrix();
+    assert_eq!(error.values, vec![vec![0, 3, 2], vec![3, 0, 5], vec![2, 5, 0]]);
+    assert_eq!(similarity.values, vec![vec![9, 7, 6], vec![7, 12, 6], vec![6, 6, 13]]);
+    assert_eq!(error.to_string(), "   1  2  3\n1  0  3  2\n2  3  0  5\n3  2  5  0");
+    let errors = day1::check("3   4   3\n4   3\n2   5   1\n");
+    assert_eq!(errors.iter().map(|e| e.line).collect::<Vec<_>>(), vec![Some(2)]);
+}
+
+#[test]
+fn test_metrics() {
+    let lists = day1::parse(include_str!("../test.txt")).unwrap();
+    let scores: Vec<String> = lists.scores(&day1::metrics()).iter().map(|m| m.values[0][1].to_string()).collect();
+    assert_eq!(scores, vec!["11", "31", "35", "5", "-0.0968", "-0.0833", "0.3333"]);
+    assert!(day1::metric("manhattan").is_none());
+}
+
+#[test]
+fn test_delimiters() {
+    let expected = day1::parse(include_str!("../test.txt")).unwrap().sorted().error_and_similarity(0, 1);
+    for text in ["3 4\n4  3\n2 5\n1\t3\n3 9\n3 3\n", "3,4\n4, 3\n2 ,5\n1,3\n3,9\n3,3\n", "3\t4\n4\t3\n2\t5\n1\t3\n3\t9\n3\t3\n"] {
+        assert_eq!(day1::parse(text).unwrap().sorted().error_and_similarity(0, 1), expected, "{:?}", text);
+    }
+    let format = day1::Format {
+        delimiter: None,
+        header: true
+    };
+    let lists = format.parse("left,right\n-3,4\n170141183460469231731687303715884105727,-4\n").unwrap().sorted();
+    assert_eq!(lists.column(0), [-3, i128::MAX]);
+    assert_eq!(lists.error_and_similarity(0, 1), (1 + (i128::MAX as u128 - 4), 0));
+    assert!(day1::parse("left,right\n3,4\n").is_err());
+    let errors = format.check("a,b\n3,4\n4;3\n5,x\n1,2,3\n");
+    assert_eq!(errors.iter().map(|e| (e.line, e.column)).collect::<Vec<_>>(), vec![(Some(3), None), (Some(4), Some(3)), (Some(5), None)]);
+    // a bad first row doesn't decide the delimiter for the rows after it
+    let errors = day1::check("3\tx\n4   3\n2   5\n");
+    assert_eq!(errors.iter().map(|e| (e.line, e.column)).collect::<Vec<_>>(), vec![(Some(1), Some(3))]);
+}
+
+#[test]
+fn test_report() {
+    let lists = day1::parse(include_str!("../test.txt")).unwrap().sorted();
+    let report = lists.report(0, 1, 3);
+    let divergent: Vec<(usize, u128)> = report.divergent.iter().map(|p| (p.rank, p.distance())).collect();
+    assert_eq!(divergent, vec![(6, 5), (1, 2), (5, 2)]);
+    assert_eq!(report.only_in_a, vec![(1, 1), (2, 1)]);
+    assert_eq!(report.only_in_b, vec![(5, 1), (9, 1)]);
+    // 3 and 4 are in both lists as often, and only the top 3 of the other 4 are kept
+    assert_eq!(report.differing, 4);
+    assert_eq!(report.frequencies.iter().map(|f| f.number).collect::<Vec<_>>(), vec![1, 2, 5]);
+    assert_eq!(report.frequencies[2], day1::Frequency {
+        number: 5,
+        a: 0,
+        b: 1
+    });
+    let report = day1::parse("1 2\n1 2\n1 2\n2 3\n").unwrap().report(0, 1, 2);
+    assert_eq!(report.frequencies.iter().map(|f| (f.number, f.difference())).collect::<Vec<_>>(), vec![(1, 3), (2, 2)]);
+    // asking for more than there are gives every pair and number
+    let everything = lists.report(0, 1, usize::MAX);
+    assert_eq!((everything.divergent.len(), everything.frequencies.len()), (6, 4));
+    assert!(report.to_string().ends_with("Frequencies (the 2 of 3 numbers whose counts differ most):\nNumber  List 1  List 2  Difference\n     1       3       0           3\n     2       1       3           2"), "{}", report);
+}
@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+use proptest::prelude::*;
+use day1::{Frequency, Lists, Pair, Score, metric};
+
+// small numbers (either side of 0), so that the lists share plenty of them (and repeat them)
+fn lists() -> impl Strategy<Value = (Vec<i128>, Vec<i128>)> {
+    (0..50usize).prop_flat_map(|n| (prop::collection::vec(-5..5i128, n), prop::collection::vec(-5..5i128, n)))
+}
+
+proptest! {
+    #[test]
+    fn single_pass_matches_separate((a, b) in lists()) {
+        let text: String = a.iter().zip(&b).map(|(a, b)| format!("{}   {}\n", a, b)).collect();
+        let lists: Lists = text.parse().unwrap();
+        let naive: i128 = a.iter().map(|x| x * b.iter().filter(|y| *y == x).count() as i128).sum();
+        prop_assert_eq!(lists.similarity(0, 1), naive);
+        let lists = lists.sorted();
+        prop_assert_eq!(lists.error_and_similarity(0, 1), (lists.error(0, 1), naive));
+    }
+
+    #[test]
+    fn kendall_matches_every_pair((a, b) in lists()) {
+        // the sign of each pair of rows in each list, where both aren't tied
+        let mut concordant = 0.0;
+        let mut discordant = 0.0;
+        let mut untied_a: f64 = 0.0;
+        let mut untied_b = 0.0;
+        for i in 0..a.len() {
+            for j in (i + 1)..a.len() {
+                let x = (a[i] - a[j]).signum();
+                let y = (b[i] - b[j]).signum();
+                if x * y > 0 { concordant += 1.0 } else if x * y < 0 { discordant += 1.0 }
+                if x != 0 { untied_a += 1.0 }
+                if y != 0 { untied_b += 1.0 }
+            }
+        }
+        let expected = (concordant - discordant) / (untied_a * untied_b).sqrt();
+        let Score::Ratio(tau) = metric("kendall").unwrap().measure(&a, &b) else { panic!("kendall should be a ratio") };
+        prop_assert!((tau.is_nan() && expected.is_nan()) || (tau - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn report_matches_sorting_every_pair((a, b) in lists(), top in 0..10usize) {
+        let text: String = a.iter().zip(&b).map(|(a, b)| format!("{} {}\n", a, b)).collect();
+        let lists: Lists = text.parse::<Lists>().unwrap().sorted();
+        let report = lists.report(0, 1, top);
+        let mut pairs: Vec<Pair> = lists.column(0).iter().zip(lists.column(1)).enumerate().map(|(i, (a, b))| Pair {
+            rank: i + 1,
+            a: *a,
+            b: *b
+        }).collect();
+        pairs.sort_by_key(|p| (std::cmp::Reverse(p.distance()), p.rank));
+        pairs.truncate(top);
+        prop_assert_eq!(report.divergent, pairs);
+        let mut counts: BTreeMap<i128, (usize, usize)> = BTreeMap::new();
+        for n in &a {
+            counts.entry(*n).or_default().0 += 1;
+        }
+        for n in &b {
+            counts.entry(*n).or_default().1 += 1;
+        }
+        let mut differing: Vec<Frequency> = counts.iter().filter(|(_, (a, b))| a != b).map(|(n, (a, b))| Frequency {
+            number: *n,
+            a: *a,
+            b: *b
+        }).collect();
+        prop_assert_eq!(report.differing, differing.len());
+        prop_assert_eq!(&report.only_in_a, &differing.iter().filter(|f| f.b == 0).map(|f| (f.number, f.a)).collect::<Vec<_>>());
+        prop_assert_eq!(&report.only_in_b, &differing.iter().filter(|f| f.a == 0).map(|f| (f.number, f.b)).collect::<Vec<_>>());
+        differing.sort_by_key(|f| (std::cmp::Reverse(f.difference()), f.number));
+        differing.truncate(top);
+        prop_assert_eq!(report.frequencies, differing);
+    }
+}
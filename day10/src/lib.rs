@@ -0,0 +1,109 @@
+//! Day 10: Hoof It
+
+use std::str::FromStr;
+use std::collections::HashSet;
+use grid::{Grid, Image, Pos, Rgb};
+use common::ParseError;
+
+/// A topographic map, with the height (0-9) of each position
+pub struct Map {
+    heights: Grid<usize>
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let heights = Grid::parse(text, height)?;
+        Ok(Self {
+            heights
+        })
+    }
+}
+
+impl Map {
+    /// Every trail head (height 0), with its score (the number of 9s it can reach) and rating (the number of trails to 9s)
+    pub fn trail_heads(&self) -> Vec<(Pos, usize, usize)> {
+        let mut v = Vec::new();
+        for pos in self.heights.positions_where(|h| *h == 0) {
+            let trail_ends = self.trail_ends(pos, 0, 9);
+            let trails = self.trails(pos, 0, 9);
+            v.push((pos, trail_ends.len(), trails));
+        }
+        v
+    }
+
+    fn trail_ends(&self, at: Pos, from: usize, to: usize) -> HashSet<Pos> {
+        let mut ends = HashSet::new();
+        if from == to {
+            ends.insert(at);
+        } else {
+            for adj in self.heights.adjacent(&at) {
+                if self.heights[adj] == from + 1 {
+                    for end in self.trail_ends(adj, from + 1, to) {
+                        ends.insert(end);
+                    }
+                }
+            }
+        }
+        ends
+    }
+
+    fn trails(&self, at: Pos, from: usize, to: usize) -> usize {
+        if from == to {
+            1
+        } else {
+            let mut count = 0;
+            for adj in self.heights.adjacent(&at) {
+                if self.heights[adj] == from + 1 {
+                    count += self.trails(adj, from + 1, to);
+                }
+            }
+            count
+        }
+    }
+
+}
+
+fn height(ch: char) -> Result<usize, ParseError> {
+    if ch == '.' {
+        Ok(100) // impassable
+    } else {
+        ch.to_digit(10).map(|d| d as usize).ok_or(ParseError::new("a height or .", ch.to_string()))
+    }
+}
+
+/// The map, shaded from brown (height 0) to white (height 9), with every trail head which reaches a 9 in green
+pub fn image(input: &str, scale: usize) -> Result<Image, ParseError> {
+    let map = parse(input)?;
+    let mut image = Image::with_palette(&map.heights, scale, |h| {
+        if *h > 9 { Rgb::BLACK } else { Rgb::BROWN.blend(Rgb::WHITE, *h as f64 / 9.0) }
+    });
+    for (pos, score, _) in map.trail_heads() {
+        if score > 0 {
+            image.paint(&pos, Rgb::GREEN);
+        }
+    }
+    Ok(image)
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    input.parse()
+}
+
+/// Every line which isn't a row of heights as wide as the first
+pub fn check(input: &str) -> Vec<ParseError> {
+    Grid::check(input, height)
+}
+
+/// The sum of the scores of every trail head
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let map = parse(input)?;
+    Ok(map.trail_heads().iter().map(|(_, score, _)| score).sum())
+}
+
+/// The sum of the ratings of every trail head
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let map = parse(input)?;
+    Ok(map.trail_heads().iter().map(|(_, _, rating)| rating).sum())
+}
@@ -0,0 +1,13 @@
+#[test]
+fn test1() {
+    let input = include_str!("../test1.txt");
+    assert_eq!(day10::part1(input).unwrap(), 4);
+    assert_eq!(day10::part2(input).unwrap(), 13);
+}
+
+#[test]
+fn test2() {
+    let input = include_str!("../test2.txt");
+    assert_eq!(day10::part1(input).unwrap(), 36);
+    assert_eq!(day10::part2(input).unwrap(), 81);
+}
@@ -0,0 +1,95 @@
+//! Day 11: Plutonian Pebbles
+
+use std::str::FromStr;
+use common::{Cache, ParseError, parse_number, verbose};
+
+/// The numbers engraved on a line of stones
+#[derive(Debug)]
+pub struct Stones {
+    stones: Vec<usize>
+}
+
+impl FromStr for Stones {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let line = text.trim_end(); // ignore a trailing line ending
+        let stones = line.split(" ").map(|s| parse_number(line, s)).collect::<Result<_, _>>()?;
+        Ok(Self {
+            stones
+        })
+    }
+}
+
+impl Stones {
+    pub fn new(stones: Vec<usize>) -> Self {
+        Self {
+            stones
+        }
+    }
+
+    /// The number of stones there will be after blinking this many times
+    pub fn blink(&self, blinks: usize) -> usize {
+        let mut count = 0;
+        let mut cache = Cache::new();
+        for stone in &self.stones {
+            count += Self::count_stones(&mut cache, *stone, blinks);
+        }
+        verbose!("Stone cache: {}", cache.stats());
+        count
+    }
+
+    /// The number of stones one stone becomes after blinking this many times, remembering results in `cache` to reuse later
+    pub fn count_stones(cache: &mut Cache<(usize, usize), usize>, stone: usize, blinks: usize) -> usize {
+        if blinks == 0 {
+            // finished
+            1
+        } else if let Some(cached_result) = cache.get(&(stone, blinks)) {
+            // cached
+            *cached_result
+        } else {
+            // calculate the result of blinks
+            let result = if stone == 0 {
+                Self::count_stones(cache, 1, blinks - 1)
+            } else {
+                let stone_str = stone.to_string();
+                if stone_str.len().is_multiple_of(2) {
+                    let middle = stone_str.len() / 2;
+                    let left_stone = stone_str[0..middle].parse().unwrap();
+                    let right_stone = stone_str[middle..stone_str.len()].parse().unwrap();
+                    Self::count_stones(cache, left_stone, blinks - 1) + Self::count_stones(cache, right_stone, blinks - 1)
+                } else {
+                    Self::count_stones(cache, stone * 2024, blinks - 1)
+                }
+            };
+            // cache this result
+            cache.insert((stone, blinks), result);
+            result
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Stones, ParseError> {
+    input.parse()
+}
+
+/// What's wrong with the only line, if anything
+pub fn check(input: &str) -> Vec<ParseError> {
+    parse(input).err().into_iter().collect()
+}
+
+/// The number of stones after blinking this many times
+pub fn blink(input: &str, blinks: usize) -> Result<usize, ParseError> {
+    let stones = parse(input)?;
+    Ok(stones.blink(blinks))
+}
+
+/// The number of stones after 25 blinks
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    blink(input, 25)
+}
+
+/// The number of stones after 75 blinks
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    blink(input, 75)
+}
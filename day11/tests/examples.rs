@@ -0,0 +1,6 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day11::part1(input).unwrap(), 55312);
+    assert_eq!(day11::part2(input).unwrap(), 65601038650482);
+}
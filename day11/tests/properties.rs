@@ -0,0 +1,35 @@
+use proptest::prelude::*;
+use common::{Cache, Eviction};
+use day11::Stones;
+
+// numbers of every length, so some split and some get multiplied
+fn stone() -> impl Strategy<Value = usize> {
+    prop_oneof![0..10usize, 10..10_000usize, 10_000..100_000_000usize]
+}
+
+proptest! {
+    #[test]
+    fn count_stones_is_additive(a in prop::collection::vec(stone(), 0..5), b in prop::collection::vec(stone(), 0..5), blinks in 0..30usize) {
+        let both: Vec<usize> = a.iter().chain(b.iter()).copied().collect();
+        prop_assert_eq!(Stones::new(both).blink(blinks), Stones::new(a).blink(blinks) + Stones::new(b).blink(blinks));
+    }
+
+    #[test]
+    fn count_stones_is_the_same_with_a_shared_cache(stones in prop::collection::vec(stone(), 1..5), blinks in 0..30usize) {
+        let mut shared = Cache::unbounded();
+        for stone in stones {
+            let alone = Stones::count_stones(&mut Cache::unbounded(), stone, blinks);
+            prop_assert_eq!(Stones::count_stones(&mut shared, stone, blinks), alone);
+        }
+    }
+
+    // fewer blinks, since a small cache forgets most of what it would reuse
+    #[test]
+    fn count_stones_is_the_same_with_a_bounded_cache(stone in stone(), blinks in 0..15usize, capacity in 0..20usize, lru in any::<bool>()) {
+        let eviction = if lru { Eviction::LeastRecentlyUsed } else { Eviction::FirstInFirstOut };
+        let mut bounded = Cache::bounded(capacity, eviction);
+        let count = Stones::count_stones(&mut bounded, stone, blinks);
+        prop_assert_eq!(count, Stones::count_stones(&mut Cache::unbounded(), stone, blinks));
+        prop_assert!(bounded.stats().peak <= capacity);
+    }
+}
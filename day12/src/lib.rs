@@ -0,0 +1,215 @@
+//! Day 12: Garden Groups
+
+use std::str::FromStr;
+use std::collections::HashSet;
+use grid::{Grid, Image, Palette, Rgb, SignedPos};
+use common::ParseError;
+
+/// A type of plant, A-Z
+#[derive(Copy, Clone, PartialEq)]
+pub struct Plant(pub char);
+
+/// The garden, with the plant growing in each position
+pub struct Map {
+    plants: Grid<Plant>
+}
+
+/// A connected area of the same type of plant
+pub struct Region {
+    pub plant: Plant,
+    locations: HashSet<SignedPos>
+}
+
+impl Plant {
+    fn from_char(ch: char) -> Result<Self, ParseError> {
+        if ch.is_ascii_uppercase() {
+            Ok(Self(ch))
+        } else {
+            Err(ParseError::new("a plant (A-Z)", ch.to_string()))
+        }
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let plants = Grid::parse(text, Plant::from_char)?;
+        Ok(Self {
+            plants
+        })
+    }
+}
+
+impl Map {
+    fn at(&self, location: &SignedPos) -> Option<Plant> {
+        self.plants.get_signed(location).copied()
+    }
+
+    /// Every region, where two regions of the same plant are never adjacent
+    pub fn regions(&self) -> Vec<Region> {
+        let mut regions = Vec::new();
+        for plant in Plant::iter() {
+            let mut locations = self.locate(plant);
+            while !locations.is_empty() {
+                let mut region = Region {
+                    plant,
+                    locations: HashSet::new()
+                };
+                let start = *locations.iter().next().unwrap();
+                locations.remove(&start);
+                region.expand(&start, self);
+                for l in &region.locations {
+                    locations.remove(l);
+                }
+                regions.push(region);
+            }
+        }
+        regions
+    }
+
+    fn locate(&self, plant: Plant) -> HashSet<SignedPos> {
+        self.plants.positions_where(|p| *p == plant).into_iter().map(SignedPos::from).collect()
+    }
+}
+
+impl Region {
+    fn expand(&mut self, start: &SignedPos, map: &Map) {
+        if self.locations.insert(*start) {
+            for adj in start.adjacent() {
+                if map.at(&adj) == Some(self.plant) {
+                    self.expand(&adj, map);
+                }
+            }
+        }
+    }
+
+    pub fn area(&self) -> usize {
+        self.locations.len()
+    }
+
+    /// The number of edges between this region and anything else
+    pub fn perimeter(&self) -> usize {
+        let mut perimeter = 0;
+        for l in &self.locations {
+            for a in l.adjacent() {
+                if !self.locations.contains(&a) {
+                    perimeter += 1;
+                }
+            }
+        }
+        perimeter
+    }
+
+    // fn edges(&self) -> HashSet<SignedPos> {
+    //     let mut edges = HashSet::new();
+    //     for l in &self.locations {
+    //         if l.adjacent().iter().any(|a| !self.locations.contains(&a)) {
+    //             edges.insert(*l);
+    //         }
+    //     }
+    //     edges
+    // }
+
+    /// The number of corners, which is also the number of straight sides
+    pub fn corners(&self) -> usize {
+        let mut corner_thirds = 0;
+        for l in &self.locations {
+            for (count, diagonal) in self.count_corner_adjacent_locations(l) {
+                if count == 0 {
+                    // obtuse corner, this is the only time it will get counted, so it counts for 3 thirds
+                    corner_thirds += 3;
+                } else if count == 1 && diagonal {
+                    // diagonal corner, this is the only time it will get counted, so it counts for 3 thirds
+                    corner_thirds += 3;
+                } else if count == 2 {
+                    // acute corner, this will get counted 3 times, so it counts for 1 third
+                    corner_thirds += 1;
+                }
+                // count == 3 or count == 1(non-diagonal) means its not a corner
+            }
+        }
+        corner_thirds / 3 // because some corners are counted 3 times, we count them in thirds
+    }
+
+    fn count_corner_adjacent_locations(&self, p: &SignedPos) -> [(usize, bool); 4] {
+        // for each of the 4 corners of p, count how many (0-3) locations are adjacent other than p, and return if the diagonal is one of them
+        // 1 2 3
+        // 4 p 6
+        // 7 8 9
+        // ie. [locations.contains(4,1,2), locations.contains(2,3,6), ..(6,9,8), ..(8,7,4)]
+        let mut counts = [(0, false); 4];
+        let mut c = 0;
+        for delta_row in [-1, 1] {
+            for delta_col in [-1, 1] {
+                if self.locations.contains(&p.delta(delta_row, 0)) {
+                    counts[c].0 += 1;
+                }
+                if self.locations.contains(&p.delta(delta_row, delta_col)) {
+                    counts[c].0 += 1;
+                    counts[c].1 = true;
+                }
+                if self.locations.contains(&p.delta(0, delta_col)) {
+                    counts[c].0 += 1;
+                }
+                c += 1;
+            }
+        }
+        counts
+    }
+}
+
+impl Plant {
+    fn iter() -> Vec<Self> {
+        let mut v = Vec::new();
+        let a = b'A';
+        for i in a..(a+26) {
+            v.push(Plant(i as char));
+        }
+        v
+    }
+}
+
+impl Palette for Plant {
+    fn colour(&self) -> Rgb {
+        // anything other than A-Z (which parsing never gives) shares A's colour
+        Rgb::indexed((self.0 as usize).saturating_sub('A' as usize))
+    }
+}
+
+/// The garden, with each type of plant in its own colour
+pub fn image(input: &str, scale: usize) -> Result<Image, ParseError> {
+    let map = parse(input)?;
+    Ok(Image::new(&map.plants, scale))
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    input.parse()
+}
+
+/// Every line which isn't a row of plants as wide as the first
+pub fn check(input: &str) -> Vec<ParseError> {
+    Grid::check(input, Plant::from_char)
+}
+
+/// The total price of fencing every region, by area times perimeter and by area times number of sides
+pub fn prices(input: &str) -> Result<(usize, usize), ParseError> {
+    let map = parse(input)?;
+    let mut by_perimeter = 0;
+    let mut by_sides = 0;
+    for r in map.regions() {
+        by_perimeter += r.area() * r.perimeter();
+        by_sides += r.area() * r.corners();
+    }
+    Ok((by_perimeter, by_sides))
+}
+
+/// The total price of fencing, by perimeter
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(prices(input)?.0)
+}
+
+/// The total price of fencing, by number of sides
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(prices(input)?.1)
+}
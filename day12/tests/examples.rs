@@ -0,0 +1,42 @@
+#[test]
+fn test1() {
+    let input = include_str!("../test1.txt");
+    assert_eq!(day12::part1(input).unwrap(), 140);
+    assert_eq!(day12::part2(input).unwrap(), 80);
+}
+
+#[test]
+fn test2() {
+    let input = include_str!("../test2.txt");
+    assert_eq!(day12::part1(input).unwrap(), 772);
+    assert_eq!(day12::part2(input).unwrap(), 436);
+}
+
+#[test]
+fn test3() {
+    let input = include_str!("../test3.txt");
+    assert_eq!(day12::part1(input).unwrap(), 1930);
+    assert_eq!(day12::part2(input).unwrap(), 1206);
+}
+
+#[test]
+fn test4() {
+    let input = include_str!("../test4.txt");
+    assert_eq!(day12::part1(input).unwrap(), 692);
+    assert_eq!(day12::part2(input).unwrap(), 236);
+}
+
+#[test]
+fn test5() {
+    let input = include_str!("../test5.txt");
+    assert_eq!(day12::part1(input).unwrap(), 1184);
+    assert_eq!(day12::part2(input).unwrap(), 368);
+}
+
+#[test]
+fn test_palette() {
+    use grid::{Palette, Rgb};
+    assert_eq!(day12::Plant('C').colour(), Rgb::indexed(2));
+    assert_eq!(day12::Plant('a').colour(), Rgb::indexed(32));
+    assert_eq!(day12::Plant('1').colour(), Rgb::indexed(0));
+}
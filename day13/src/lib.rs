@@ -0,0 +1,318 @@
+//! Day 13: Claw Contraption
+
+use std::str::FromStr;
+use gcd::Gcd;
+use std::collections::HashSet;
+use common::{ParseError, parse_number, sections};
+
+/// A claw machine, with how far each button moves the claw and where the prize is
+#[derive(Debug)]
+pub struct Claw {
+    a_delta: Pos,
+    b_delta: Pos,
+    target: Pos
+}
+
+#[derive(Debug, Clone)]
+struct Pos {
+    row: usize,
+    col: usize
+}
+
+/// How many times to press each button
+#[derive(Debug)]
+pub struct Presses {
+    pub a: usize,
+    pub b: usize
+}
+
+impl Presses {
+    /// The tokens it takes, where A costs 3 and B costs 1
+    pub fn cost(&self) -> u128 {
+        3 * self.a as u128 + self.b as u128
+    }
+}
+
+impl FromStr for Claw {
+    type Err = ParseError;
+
+    fn from_str(section: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = section.lines().collect();
+        if lines.len() != 3 {
+            return Err(ParseError::new("3 lines (Button A, Button B, Prize)", format!("{} lines", lines.len())));
+        }
+        let mut positions = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            positions.push(line.parse::<Pos>().map_err(|e| e.with_line(i + 1))?);
+        }
+        let target = positions.pop().unwrap();
+        let b_delta = positions.pop().unwrap();
+        let a_delta = positions.pop().unwrap();
+        Ok(Self {
+            a_delta,
+            b_delta,
+            target
+        })
+    }
+}
+
+impl FromStr for Pos {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        //eg. Button A: X+94, Y+34
+        //eg. Prize: X=8400, Y=5400
+        let numbers = line.split(": ").nth(1).ok_or(ParseError::new("a label followed by \": \"", line))?;
+        let numbers: Vec<&str> = numbers.split(", ").collect();
+        if numbers.len() != 2 {
+            return Err(ParseError::new("X and Y separated by \", \"", line));
+        }
+        let row = parse_number(line, numbers[0].get(2..).unwrap_or(""))?;
+        let col = parse_number(line, numbers[1].get(2..).unwrap_or(""))?;
+        Ok(Self {
+            row,
+            col
+        })
+    }
+}
+
+impl Pos {
+    fn add(&mut self, delta: &Pos) {
+        self.row += delta.row;
+        self.col += delta.col;
+    }
+}
+
+impl Claw {
+    /// The presses which reach the prize, if any
+    pub fn win(&self) -> Option<Presses> {
+        let row = LinearEquation::new(self.a_delta.row, self.b_delta.row, self.target.row).solve()?;
+        let col = LinearEquation::new(self.a_delta.col, self.b_delta.col, self.target.col).solve()?;
+        let mut row_col = LinearEquation::new(self.a_delta.row + self.a_delta.col, self.b_delta.row + self.b_delta.col, self.target.row + self.target.col).solve()?;
+        row_col.limit(&row.x_range, &row.y_range)?;
+        row_col.limit(&col.x_range, &col.y_range)?;
+        let (a,b) = row_col.first_satifying(&row, &col)?;
+        Some(Presses { a, b })
+    }
+
+    /// The cheapest of every solution which works for both the row and the col (much slower than win)
+    pub fn win_by_enumeration(&self) -> Option<Presses> {
+        let row = LinearEquation::new(self.a_delta.row, self.b_delta.row, self.target.row).solve()?;
+        let col = LinearEquation::new(self.a_delta.col, self.b_delta.col, self.target.col).solve()?;
+        row.all().intersection(&col.all()).map(|(a, b)| Presses { a: *a, b: *b }).min_by_key(|p| p.cost())
+    }
+}
+
+struct LinearEquation {
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize
+}
+
+impl LinearEquation {
+    // https://math.libretexts.org/Courses/Mount_Royal_University/Higher_Arithmetic/5%3A_Diophantine_Equations/5.1%3A_Linear_Diophantine_Equations
+    // ax + by = c
+
+    fn new(a: usize, b: usize, c: usize) -> Self {
+        let d = a.gcd(b);
+        Self {
+            a, b, c, d
+        }
+    }
+
+    fn has_soln(&self) -> bool {
+        self.c.rem_euclid(self.d) == 0
+    }
+
+    fn solve(&self) -> Option<LinearSolution> {
+        if self.has_soln() {
+            let mut soln = LinearSolution::from(self);
+            let x = LinearRange {
+                min: 0.0,
+                max: self.c as f64 / self.a as f64
+            };
+            let y = LinearRange {
+                min: 0.0,
+                max: self.c as f64 / self.b as f64
+            };
+            soln.limit(&x, &y)?;
+            Some(soln)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug)]
+struct LinearSolution {
+    a: isize,
+    b: isize,
+    c: isize,
+    d: isize,
+    x0: isize,
+    y0: isize,
+    x_range: LinearRange,
+    y_range: LinearRange,
+}
+
+impl LinearSolution {
+    fn from(equation: &LinearEquation) -> Self {
+        let mut x0 = 0;
+        let a = equation.a as isize;
+        let b = equation.b as isize;
+        let c = equation.c as isize;
+        let d = equation.d as isize;
+        loop {
+            let numerator = d - x0 * a;
+            let denominator = b;
+            if numerator.rem_euclid(denominator) == 0 {
+                let y0 = numerator / denominator;
+                let min_m = -c * y0 / a;
+                let max_m = c * x0 / b;
+                let x1 = c as f64 * x0 as f64 / d as f64 - min_m as f64 * b as f64 / d as f64;
+                let x2 = c as f64 * x0 as f64 / d as f64 - max_m as f64 * b as f64 / d as f64;
+                let y1 = a as f64 * min_m as f64 / d as f64 + c as f64 * y0 as f64 / d as f64;
+                let y2 = a as f64 * max_m as f64 / d as f64 + c as f64 * y0 as f64 / d as f64;
+                let x_range = LinearRange::from(x1, x2);
+                let y_range = LinearRange::from(y1, y2);
+                return Self {
+                    a, b, c, d, x0, y0, x_range, y_range
+                };
+            }
+            x0 += 1;
+        }
+    }
+
+    fn limit(&mut self, x: &LinearRange, y: &LinearRange) -> Option<()> {
+        self.x_range = self.x_range.overlap(x)?;
+        self.y_range = self.y_range.overlap(y)?;
+        Some(())
+    }
+
+    fn all(&self) -> HashSet<(usize, usize)> {
+        let (min_m, max_m) = self.get_m_range();
+        let mut solutions = HashSet::new();
+        for m in min_m..(max_m + 1) {
+            let x = self.c * self.x0 / self.d - m * self.b / self.d;
+            let y = self.a * m / self.d + self.c * self.y0 / self.d;
+            if self.is_solution(x, y) {
+                solutions.insert((x as usize, y as usize));
+            }
+        }
+        solutions
+    }
+
+    fn first_satifying(&self, other1: &LinearSolution, other2: &LinearSolution) -> Option<(usize, usize)> {
+        let (min_m, max_m) = self.get_m_range();
+        for m in min_m..(max_m + 1) {
+            let x = self.c * self.x0 / self.d - m * self.b / self.d;
+            let y = self.a * m / self.d + self.c * self.y0 / self.d;
+            if other1.is_solution(x, y) && other2.is_solution(x, y) {
+                return Some((x as usize, y as usize));
+            }
+        }
+        None
+    }
+
+    fn is_solution(&self, x: isize, y: isize) -> bool {
+        self.a * x + self.b * y == self.c
+    }
+
+    fn get_m_range(&self) -> (isize, isize) {
+        let m_x1 = self.c as f64 * self.x0 as f64 / self.b as f64 - self.x_range.min * self.d as f64 / self.b as f64;
+        let m_x2 = self.c as f64 * self.x0 as f64 / self.b as f64 - self.x_range.max * self.d as f64 / self.b as f64;
+        let (m_min_x, m_max_x) = if m_x1 > m_x2 {
+            (m_x2, m_x1)
+        } else {
+            (m_x1, m_x2)
+        };
+        let m_y1 = self.y_range.min * self.d as f64 / self.a as f64 - self.c as f64 * self.y0 as f64 / self.a as f64;
+        let m_y2 = self.y_range.max * self.d as f64 / self.a as f64 - self.c as f64 * self.y0 as f64 / self.a as f64;
+        let (m_min_y, m_max_y) = if m_y1 > m_y2 {
+            (m_y2, m_y1)
+        } else {
+            (m_y1, m_y2)
+        };
+        let min_m = m_min_x.max(m_min_y);
+        let max_m = m_max_x.min(m_max_y);
+        (min_m.ceil() as isize, max_m.floor() as isize)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct LinearRange {
+    min: f64,
+    max: f64
+}
+
+impl LinearRange {
+    fn from(a: f64, b: f64) -> Self {
+        if a > b {
+            Self {
+                min: b,
+                max: a
+            }
+        } else {
+            Self {
+                min: a,
+                max: b
+            }
+        }
+    }
+
+    fn overlap(&self, other: &Self) -> Option<Self> {
+        let a1 = self.min;
+        let b1 = self.max;
+        let a2 = other.min;
+        let b2 = other.max;
+        if a2 <= a1 && b2 >= b1 {
+            Some(*self)
+        } else if a2 >= a1 && b2 <= b1 {
+            Some(*other)
+        } else if (a2 < a1 && b2 < a1) || (a2 > b1 && b2 > b1) {
+            None
+        } else if a2 <= a1 && b2 >= a1 {
+            Some(Self { min: a1, max: b2 })
+        } else if a2 <= b1 && b2 >= b1 {
+            Some(Self { min: a2, max: b1 })
+        } else {
+            panic!()
+        }
+    }
+}
+
+/// One claw machine per section
+pub fn parse(input: &str) -> Result<Vec<Claw>, ParseError> {
+    sections(input).iter().map(|s| s.parse()).collect()
+}
+
+/// Every line which isn't a button or prize, or if a machine's lines are all fine, whatever else is wrong with it (eg. a missing line)
+pub fn check(input: &str) -> Vec<ParseError> {
+    sections(input).iter()
+        .flat_map(|s| common::check(s.check_lines(Pos::from_str), || s.parse::<Claw>()))
+        .collect()
+}
+
+fn tokens(claws: &[Claw]) -> u128 {
+    claws.iter().filter_map(|c| c.win()).map(|p| p.cost()).sum()
+}
+
+/// The fewest tokens it takes to win every prize which can be won
+pub fn part1(input: &str) -> Result<u128, ParseError> {
+    Ok(tokens(&parse(input)?))
+}
+
+/// The same as part 1, but by enumerating every solution rather than searching for the first (too slow for part 2)
+pub fn part1_by_enumeration(input: &str) -> Result<u128, ParseError> {
+    Ok(parse(input)?.iter().filter_map(|c| c.win_by_enumeration()).map(|p| p.cost()).sum())
+}
+
+/// The same as part 1, with every prize 10000000000000 further away in both directions
+pub fn part2(input: &str) -> Result<u128, ParseError> {
+    let mut claws = parse(input)?;
+    for claw in &mut claws {
+        claw.target.add(&Pos { row: 10000000000000, col: 10000000000000 });
+    }
+    Ok(tokens(&claws))
+}
@@ -0,0 +1,10 @@
+// the search for the first solution should find the same prizes as enumerating every solution
+#[test]
+fn first_satisfying_matches_all() {
+    generate::assert_agree(13, 20, 20, |input| day13::part1(input).unwrap(), |input| day13::part1_by_enumeration(input).unwrap());
+}
+
+#[test]
+fn enumeration_solves_example() {
+    assert_eq!(day13::part1_by_enumeration(include_str!("../test.txt")).unwrap(), 480);
+}
@@ -0,0 +1,12 @@
+#[test]
+fn test_part1() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day13::part1(input).unwrap(), 480);
+}
+
+#[test]
+#[ignore = "slow, takes ~30s even when optimised"]
+fn test_part2() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day13::part2(input).unwrap(), 875318608908);
+}
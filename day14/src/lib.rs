@@ -0,0 +1,224 @@
+//! Day 14: Restroom Redoubt
+
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use grid::{Grid, Image, Pos, Rgb, SignedPos, Simulation, Size};
+use common::{ParseError, parse_number, split_sections};
+
+/// The bathroom, with every robot's position and velocity (where positions wrap around the edges)
+pub struct Map {
+    size: SignedPos,
+    robots: Vec<Robot>
+}
+
+#[derive(Debug)]
+struct Robot {
+    position: SignedPos,
+    velocity: SignedPos
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let sections = split_sections(text, 2, "a size and robots separated by a blank line")?;
+        let size = parse_size(sections[0].text).map_err(|e| sections[0].locate(e))?;
+        let mut robots: Vec<Robot> = sections[1].parse_lines()?;
+        for (i, (robot, line)) in robots.iter_mut().zip(sections[1].lines()).enumerate() {
+            if !(0..size.row).contains(&robot.position.row) || !(0..size.col).contains(&robot.position.col) {
+                let expected = format!("a robot inside the {},{} room", size.col, size.row);
+                return Err(sections[1].locate(ParseError::new(expected, line).with_line(i + 1)));
+            }
+            // only where a robot ends up in the room matters, so a velocity can be made smaller than the room (and never overflow)
+            robot.velocity = SignedPos::new(robot.velocity.row.rem_euclid(size.row), robot.velocity.col.rem_euclid(size.col));
+        }
+        Ok(Self {
+            size,
+            robots
+        })
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for row in 0..self.size.row {
+            for col in 0..self.size.col {
+                let count = self.robots.iter().filter(|r| r.position == SignedPos { row, col }).count();
+                if count == 0 {
+                    write!(f, ".")?;
+                } else if count < 10 {
+                    write!(f, "{}", count)?;
+                } else {
+                    write!(f, "*")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let sections: Vec<_> = line.split(" ").collect();
+        if sections.len() != 2 {
+            return Err(ParseError::new("a robot like p=0,4 v=3,-3", line));
+        }
+        let position = parse_pos(line, sections[0].split("=").nth(1).ok_or(ParseError::at(line, sections[0], "p=X,Y"))?)?;
+        let velocity = parse_pos(line, sections[1].split("=").nth(1).ok_or(ParseError::at(line, sections[1], "v=X,Y"))?)?;
+        Ok(Self {
+            position,
+            velocity
+        })
+    }
+}
+
+fn parse_pos(line: &str, pos: &str) -> Result<SignedPos, ParseError> {
+    // 0,4 => row:4, col:0
+    let numbers: Vec<isize> = pos.split(",").map(|s| parse_number(line, s)).collect::<Result<_, _>>()?;
+    if numbers.len() != 2 {
+        return Err(ParseError::at(line, pos, "2 numbers separated by ,"));
+    }
+    Ok(SignedPos::new(numbers[1], numbers[0]))
+}
+
+// the width and height of the room, which the robots wrap around so it can't be empty
+fn parse_size(line: &str) -> Result<SignedPos, ParseError> {
+    let size = parse_pos(line, line)?;
+    if size.row <= 0 || size.col <= 0 {
+        return Err(ParseError::new("a positive width and height", line));
+    }
+    Ok(size)
+}
+
+impl Map {
+    /// Move every robot as if this many seconds have passed
+    pub fn simulate(&mut self, seconds: usize) {
+        for r in &mut self.robots {
+            r.position.row = (r.position.row + seconds as isize * r.velocity.row).rem_euclid(self.size.row);
+            r.position.col = (r.position.col + seconds as isize * r.velocity.col).rem_euclid(self.size.col);
+        }
+    }
+
+    fn count_robots(&self, from_row: isize, less_than_row: isize, from_col: isize, less_than_col: isize) -> usize {
+        let mut count = 0;
+        for r in &self.robots {
+            if r.position.row >= from_row && r.position.row < less_than_row && r.position.col >= from_col && r.position.col < less_than_col {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// The number of robots in each quadrant (not counting the middle row and column)
+    pub fn quadrants(&self) -> [usize; 4] {
+        let end = &self.size;
+        let mid = SignedPos {
+            row: end.row / 2,
+            col: end.col / 2
+        };
+        [
+            self.count_robots(0, mid.row, 0, mid.col),
+            self.count_robots(0, mid.row, mid.col + 1, end.col),
+            self.count_robots(mid.row + 1, end.row, 0, mid.col),
+            self.count_robots(mid.row + 1, end.row, mid.col + 1, end.col)
+        ]
+    }
+
+    /// The number of robots on each tile
+    pub fn density(&self) -> Grid<usize> {
+        let mut density = Grid::new(Size::new(self.size.row as usize, self.size.col as usize), 0);
+        for r in &self.robots {
+            density[Pos::new(r.position.row as usize, r.position.col as usize)] += 1;
+        }
+        density
+    }
+
+    pub fn safety_factor(&self) -> usize {
+        self.quadrants().iter().product()
+    }
+
+    /// Whether any row has this many robots in a line
+    pub fn contains_line(&self, length: usize) -> bool {
+        let lookup: HashSet<SignedPos> = self.robots.iter().map(|r| r.position).collect();
+        for row in 0..self.size.row {
+            for col in 0..self.size.col {
+                let mut line = true;
+                for i in 0..length {
+                    if !lookup.contains(&SignedPos { row, col: col + i as isize }) {
+                        line = false;
+                        break;
+                    }
+                }
+                if line {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+// the robots never stop, so neither does the simulation
+impl Simulation for Map {
+    fn step(&mut self) -> bool {
+        self.simulate(1);
+        true
+    }
+
+    fn frame(&self) -> String {
+        self.to_string()
+    }
+
+    // brighter where more robots overlap
+    fn image(&self, scale: usize) -> Image {
+        Image::with_palette(&self.density(), scale, |count| match count {
+            0 => Rgb::BLACK,
+            n => Rgb::GREEN.blend(Rgb::WHITE, (*n - 1) as f64 / 3.0)
+        })
+    }
+
+    fn status(&self) -> String {
+        format!("Safety factor {}", self.safety_factor())
+    }
+}
+
+/// The size of the bathroom (a line like 101,103), then a blank line and one robot per line
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    input.parse()
+}
+
+/// A size which isn't 2 positive numbers, and every line which isn't a robot, or if they are all fine, the first robot outside the room
+pub fn check(input: &str) -> Vec<ParseError> {
+    let sections = match split_sections(input, 2, "a size and robots separated by a blank line") {
+        Ok(sections) => sections,
+        Err(e) => return vec![e]
+    };
+    let mut errors = sections[0].check_lines(parse_size);
+    errors.extend(sections[1].check_lines(Robot::from_str));
+    common::check(errors, || parse(input))
+}
+
+/// The safety factor after 100 seconds
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let mut map = parse(input)?;
+    map.simulate(100);
+    Ok(map.safety_factor())
+}
+
+/// The first time a line of 20 robots appears (the christmas tree), if ever
+pub fn part2(input: &str) -> Result<Option<usize>, ParseError> {
+    let mut map = parse(input)?;
+    // positions repeat after rows * cols seconds
+    for i in 0..(map.size.row * map.size.col) {
+        map.simulate(1);
+        if map.contains_line(20) {
+            return Ok(Some(i as usize + 1));
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,22 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day14::part1(input).unwrap(), 12);
+    // the example robots never line up into a christmas tree
+    assert_eq!(day14::part2(input).unwrap(), None);
+}
+
+#[test]
+fn test_invalid_size() {
+    for size in ["0,0", "11,0", "-11,7"] {
+        let input = format!("{}\n\np=0,4 v=3,-3\n", size);
+        let error = day14::parse(&input).err().unwrap();
+        assert_eq!((error.line, error.expected.as_str()), (Some(1), "a positive width and height"));
+        assert_eq!(day14::check(&input), vec![error]);
+    }
+    // a robot outside the room is an error, rather than a panic when it is drawn
+    let error = day14::parse("11,7\n\np=0,4 v=3,-3\np=11,0 v=1,1\n").err().unwrap();
+    assert_eq!((error.line, error.expected.as_str()), (Some(4), "a robot inside the 11,7 room"));
+    // but any velocity is fine
+    assert_eq!(day14::part1(&format!("11,7\n\np=0,4 v={},-3\n", isize::MAX)).unwrap(), 0);
+}
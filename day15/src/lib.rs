@@ -0,0 +1,407 @@
+//! Day 15: Warehouse Woes
+
+use std::str::FromStr;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use grid::{Direction, Grid, Image, Palette, Pos, Rgb, Simulation};
+use common::{ParseError, split_sections};
+
+/// The warehouse, with its walls and boxes and where the robot is
+pub struct Map {
+    tiles: Grid<Tile>,
+    robot: Pos
+}
+
+/// The warehouse of part 2, where everything except the robot is twice as wide
+pub struct BigMap {
+    tiles: Grid<BigTile>,
+    robot: Pos
+}
+
+#[derive(PartialEq, Copy, Clone)]
+enum Tile {
+    Empty,
+    Box,
+    Wall
+}
+
+#[derive(PartialEq, Copy, Clone)]
+enum BigTile {
+    Empty,
+    LeftBox,
+    RightBox,
+    Wall
+}
+
+impl Tile {
+    fn from_char(ch: char) -> Result<Self, ParseError> {
+        match ch {
+            '#' => Ok(Self::Wall),
+            'O' => Ok(Self::Box),
+            '.' | '@' => Ok(Self::Empty),
+            _ => Err(ParseError::new("a tile (#, O, . or @)", ch.to_string()))
+        }
+    }
+}
+
+impl Tile {
+    fn to_char(self) -> char {
+        match self {
+            Self::Wall => '#',
+            Self::Box => 'O',
+            Self::Empty => '.'
+        }
+    }
+}
+
+impl BigTile {
+    fn to_char(self) -> char {
+        match self {
+            Self::Wall => '#',
+            Self::LeftBox => '[',
+            Self::RightBox => ']',
+            Self::Empty => '.'
+        }
+    }
+}
+
+impl Palette for Tile {
+    fn colour(&self) -> Rgb {
+        match self {
+            Self::Wall => Rgb::GREY,
+            Self::Box => Rgb::BROWN,
+            Self::Empty => Rgb::BLACK
+        }
+    }
+}
+
+// each half of a box is drawn slightly differently, so that boxes which are next to each other can be told apart
+impl Palette for BigTile {
+    fn colour(&self) -> Rgb {
+        match self {
+            Self::Wall => Rgb::GREY,
+            Self::LeftBox => Rgb::BROWN,
+            Self::RightBox => Rgb::BROWN.blend(Rgb::BLACK, 0.3),
+            Self::Empty => Rgb::BLACK
+        }
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(section: &str) -> Result<Self, Self::Err> {
+        let chars: Grid<char> = section.parse()?;
+        let robot = chars.position(|ch| *ch == '@').ok_or(ParseError::new("a robot (@)", ""))?;
+        let tiles = Grid::parse(section, Tile::from_char)?;
+        Ok(Self {
+            tiles,
+            robot
+        })
+    }
+}
+
+impl Map {
+    fn get(&self, pos: &Pos) -> &Tile {
+        &self.tiles[*pos]
+    }
+
+    fn set(&mut self, pos: &Pos, tile: Tile) {
+        self.tiles.set(pos, tile);
+    }
+
+    /// Move the robot in each direction in turn, pushing any boxes in its way (unless they are against a wall)
+    pub fn move_all(&mut self, directions: &Vec<Direction>) {
+        for direction in directions {
+            self.move_one(direction);
+        }
+    }
+
+    fn move_one(&mut self, direction: &Direction) {
+        let existing = self.robot;
+        if self.can_move(&existing, *direction) {
+            self.do_move(&existing, *direction);
+            self.robot = existing.step(*direction).unwrap();
+        }
+    }
+
+    fn can_move(&mut self, pos: &Pos, direction: Direction) -> bool {
+        let new_pos = pos.step(direction).unwrap();
+        match self.get(&new_pos) {
+            Tile::Wall => false,
+            Tile::Empty => true,
+            Tile::Box => self.can_move(&new_pos, direction)
+        }
+    }
+
+    fn do_move(&mut self, pos: &Pos, direction: Direction) {
+        let new_pos = pos.step(direction).unwrap();
+        match self.get(&new_pos) {
+            Tile::Wall => panic!("Tried to move a wall"),
+            Tile::Empty => {
+                self.set(&new_pos, *self.get(pos));
+                self.set(pos, Tile::Empty);
+            },
+            Tile::Box => {
+                self.do_move(&new_pos, direction);
+                self.set(&new_pos, *self.get(pos));
+                self.set(pos, Tile::Empty);
+            }
+        }
+    }
+
+    /// The sum of each box's GPS coordinate (100 times its row plus its column)
+    pub fn sum_gps(&self) -> usize {
+        self.tiles.positions_where(|t| *t == Tile::Box).iter().map(|p| p.row * 100 + p.col).sum()
+    }
+
+    /// The same warehouse, twice as wide
+    pub fn expand(&self) -> BigMap {
+        let mut tiles = Vec::new();
+        for old_row in self.tiles.to_rows() {
+            let mut new_row = Vec::new();
+            for old_tile in old_row {
+                new_row.append(&mut match old_tile {
+                    Tile::Empty => vec![BigTile::Empty, BigTile::Empty],
+                    Tile::Wall => vec![BigTile::Wall, BigTile::Wall],
+                    Tile::Box => vec![BigTile::LeftBox, BigTile::RightBox]
+                });
+            }
+            tiles.push(new_row);
+        }
+        let robot = Pos {
+            row: self.robot.row,
+            col: self.robot.col * 2
+        };
+        BigMap {
+            tiles: Grid::from_rows(tiles).expect("Every row doubles in width"),
+            robot
+        }
+    }
+}
+
+impl BigMap {
+    fn get(&self, pos: &Pos) -> &BigTile {
+        &self.tiles[*pos]
+    }
+
+    fn set(&mut self, pos: &Pos, tile: BigTile) {
+        self.tiles.set(pos, tile);
+    }
+
+    /// Move the robot in each direction in turn, pushing any boxes in its way (unless they are against a wall)
+    pub fn move_all(&mut self, directions: &Vec<Direction>) {
+        for direction in directions {
+            self.move_one(direction);
+        }
+    }
+
+    fn move_one(&mut self, direction: &Direction) {
+        let existing = self.robot;
+        if self.can_move(&existing, *direction) {
+            self.do_move(&existing, *direction);
+            self.robot = existing.step(*direction).unwrap();
+        }
+    }
+
+    fn can_move(&mut self, pos: &Pos, direction: Direction) -> bool {
+        let new_pos = pos.step(direction).unwrap();
+        match self.get(&new_pos) {
+            BigTile::Wall => false,
+            BigTile::Empty => true,
+            BigTile::LeftBox => {
+                let right_box_new_pos = new_pos.step(Direction::Right).unwrap();
+                if direction == Direction::Right {
+                    self.can_move(&right_box_new_pos, direction)
+                } else {
+                    self.can_move(&new_pos, direction) && self.can_move(&right_box_new_pos, direction)
+                }
+            },
+            BigTile::RightBox => {
+                let left_box_new_pos = new_pos.step(Direction::Left).unwrap();
+                if direction == Direction::Left {
+                    self.can_move(&left_box_new_pos, direction)
+                } else {
+                    self.can_move(&new_pos, direction) && self.can_move(&left_box_new_pos, direction)
+                }
+            }
+        }
+    }
+
+    fn do_move(&mut self, pos: &Pos, direction: Direction) {
+        let new_pos = pos.step(direction).unwrap();
+        match self.get(&new_pos) {
+            BigTile::Wall => panic!("Tried to move a wall"),
+            BigTile::Empty => {
+                self.set(&new_pos, *self.get(pos));
+                self.set(pos, BigTile::Empty);
+            },
+            BigTile::LeftBox => {
+                let right_box_new_pos = new_pos.step(Direction::Right).unwrap();
+                if direction == Direction::Right {
+                    self.do_move(&right_box_new_pos, direction);
+                    self.do_move(&new_pos, direction);
+                } else {
+                    self.do_move(&new_pos, direction);
+                    self.do_move(&right_box_new_pos, direction);
+                }
+                self.set(&new_pos, *self.get(pos));
+                self.set(pos, BigTile::Empty);
+            },
+            BigTile::RightBox => {
+                let left_box_new_pos = new_pos.step(Direction::Left).unwrap();
+                if direction == Direction::Left {
+                    self.do_move(&left_box_new_pos, direction);
+                    self.do_move(&new_pos, direction);
+                } else {
+                    self.do_move(&new_pos, direction);
+                    self.do_move(&left_box_new_pos, direction);
+                }
+                self.set(&new_pos, *self.get(pos));
+                self.set(pos, BigTile::Empty);
+            }
+        }
+    }
+
+    /// The sum of each box's GPS coordinate (100 times its row plus its column)
+    pub fn sum_gps(&self) -> usize {
+        self.tiles.positions_where(|t| *t == BigTile::LeftBox).iter().map(|p| p.row * 100 + p.col).sum()
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let mut chars = self.tiles.map(|t| t.to_char());
+        chars[self.robot] = '@';
+        write!(f, "{}", chars)
+    }
+}
+
+impl Display for BigMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let mut chars = self.tiles.map(|t| t.to_char());
+        chars[self.robot] = '@';
+        write!(f, "{}", chars)
+    }
+}
+
+/// The robot following its directions one at a time, around either size of warehouse
+pub struct Warehouse<M> {
+    map: M,
+    directions: Vec<Direction>,
+    next: usize
+}
+
+/// The robot ready to follow its directions around the warehouse of part 1
+pub fn warehouse(input: &str) -> Result<Warehouse<Map>, ParseError> {
+    let (map, directions) = parse(input)?;
+    Ok(Warehouse {
+        map,
+        directions,
+        next: 0
+    })
+}
+
+/// The robot ready to follow its directions around the wider warehouse of part 2
+pub fn big_warehouse(input: &str) -> Result<Warehouse<BigMap>, ParseError> {
+    let (map, directions) = parse(input)?;
+    Ok(Warehouse {
+        map: map.expand(),
+        directions,
+        next: 0
+    })
+}
+
+impl Simulation for Warehouse<Map> {
+    fn step(&mut self) -> bool {
+        let Some(direction) = self.directions.get(self.next) else {
+            return false;
+        };
+        self.map.move_one(direction);
+        self.next += 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        self.map.to_string()
+    }
+
+    fn image(&self, scale: usize) -> Image {
+        let mut image = Image::new(&self.map.tiles, scale);
+        image.paint(&self.map.robot, Rgb::RED);
+        image
+    }
+
+    fn status(&self) -> String {
+        format!("Move {}/{}, GPS sum {}", self.next, self.directions.len(), self.map.sum_gps())
+    }
+}
+
+impl Simulation for Warehouse<BigMap> {
+    fn step(&mut self) -> bool {
+        let Some(direction) = self.directions.get(self.next) else {
+            return false;
+        };
+        self.map.move_one(direction);
+        self.next += 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        self.map.to_string()
+    }
+
+    fn image(&self, scale: usize) -> Image {
+        let mut image = Image::new(&self.map.tiles, scale);
+        image.paint(&self.map.robot, Rgb::RED);
+        image
+    }
+
+    fn status(&self) -> String {
+        format!("Move {}/{}, GPS sum {}", self.next, self.directions.len(), self.map.sum_gps())
+    }
+}
+
+/// The map, then a blank line and the directions for the robot (on any number of lines)
+pub fn parse(text: &str) -> Result<(Map, Vec<Direction>), ParseError> {
+    let sections = split_sections(text, 2, "a map and directions separated by a blank line")?;
+    let map: Map = sections[0].parse()?;
+    let mut directions = Vec::new();
+    for (row, line) in sections[1].lines().enumerate() {
+        directions.extend(parse_directions(line).map_err(|e| e.with_line(sections[1].first_line + row))?);
+    }
+    Ok((map, directions))
+}
+
+fn parse_directions(line: &str) -> Result<Vec<Direction>, ParseError> {
+    line.chars()
+        .enumerate()
+        .map(|(col, ch)| Direction::from_arrow(ch).ok_or(ParseError::new("a direction (^, >, v or <)", ch.to_string()).with_column(col + 1)))
+        .collect()
+}
+
+/// Every line which isn't a row of tiles as wide as the first (in the map) or a line of directions, or if they are all fine, a missing robot
+pub fn check(text: &str) -> Vec<ParseError> {
+    let sections = match split_sections(text, 2, "a map and directions separated by a blank line") {
+        Ok(sections) => sections,
+        Err(e) => return vec![e]
+    };
+    let mut errors: Vec<ParseError> = Grid::check(sections[0].text, Tile::from_char).into_iter().map(|e| sections[0].locate(e)).collect();
+    errors.extend(sections[1].check_lines(parse_directions));
+    common::check(errors, || parse(text))
+}
+
+/// The sum of the GPS coordinates of the boxes, after the robot has moved
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let (mut map, directions) = parse(input)?;
+    map.move_all(&directions);
+    Ok(map.sum_gps())
+}
+
+/// The same as part 1, in the wider warehouse
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let (map, directions) = parse(input)?;
+    let mut big_map = map.expand();
+    big_map.move_all(&directions);
+    Ok(big_map.sum_gps())
+}
@@ -0,0 +1,15 @@
+// Only moving up and down, a big map behaves exactly like the small one (every box stays aligned to an even column),
+// so each big box at (r, 2c) is a small box at (r, c) and the GPS sums differ by the (unchanged) box columns
+#[test]
+fn big_map_matches_map_moving_vertically() {
+    let vertical = |input: &str| {
+        let (map, moves) = input.split_once("\n\n").unwrap();
+        format!("{}\n\n{}", map, moves.replace('<', "^").replace('>', "v"))
+    };
+    let box_columns = |input: &str| -> usize {
+        input.lines().flat_map(|line| line.match_indices('O').map(|(col, _)| col)).sum()
+    };
+    generate::assert_agree(15, 20, 20,
+        |input| day15::part2(&vertical(input)).unwrap(),
+        |input| day15::part1(&vertical(input)).unwrap() + box_columns(input));
+}
@@ -0,0 +1,21 @@
+#[test]
+fn test1() {
+    let input = include_str!("../test1.txt");
+    assert_eq!(day15::part1(input).unwrap(), 2028);
+    assert_eq!(day15::part2(input).unwrap(), 1751);
+}
+
+#[test]
+fn test2() {
+    let input = include_str!("../test2.txt");
+    assert_eq!(day15::part1(input).unwrap(), 10092);
+    assert_eq!(day15::part2(input).unwrap(), 9021);
+}
+
+#[test]
+fn test_check() {
+    assert!(day15::check(include_str!("../test2.txt")).is_empty());
+    let errors = day15::check("####\n#@x#\n####\n\n<>^\nv?<\n");
+    assert_eq!(errors.iter().map(|e| (e.line, e.column)).collect::<Vec<_>>(), vec![(Some(2), Some(3)), (Some(6), Some(2))]);
+    assert_eq!(day15::check("####\n#..#\n####\n\n<>\n")[0].expected, "a robot (@)");
+}
@@ -0,0 +1,106 @@
+//! Day 16: Reindeer Maze
+
+use std::str::FromStr;
+use pathfinding::prelude::astar_bag;
+use std::collections::HashSet;
+use grid::{Direction, Grid, Image, Pos, Rgb};
+use common::ParseError;
+
+/// The reindeer maze, where the reindeer starts facing east
+pub struct Maze {
+    start: (Pos, Direction),
+    end: Pos,
+    walls: Grid<bool>
+}
+
+impl FromStr for Maze {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let chars = Grid::parse(text, tile)?;
+        let start = chars.position(|ch| *ch == 'S').map(|p| (p, Direction::Right));
+        let end = chars.position(|ch| *ch == 'E');
+        Ok(Self {
+            walls: chars.map(|ch| *ch == '#'),
+            start: start.ok_or(ParseError::new("a start (S)", ""))?,
+            end: end.ok_or(ParseError::new("an end (E)", ""))?
+        })
+    }
+}
+
+fn minimum_cost(pos: &Pos, _facing: &Direction, end: &Pos) -> u32 {
+    // could add minimum turn cost aswell
+    pos.manhattan_distance(end) as u32
+}
+
+fn successors(pos: &Pos, facing: &Direction, walls: &Grid<bool>) -> Vec<((Pos, Direction), u32)> {
+    let mut v: Vec<((Pos, Direction), u32)> = Vec::new();
+    if let Some(new_pos) = walls.step(pos, *facing) {
+        if !walls[new_pos] {
+            v.push(((new_pos, *facing), 1));
+        }
+    }
+    v.push(((*pos, facing.rotate_right()), 1000));
+    v.push(((*pos, facing.rotate_left()), 1000));
+    v
+}
+
+impl Maze {
+    /// The minimum cost, and every position on any path with that cost (if the end can be reached)
+    pub fn best_paths(&self) -> Option<(u32, HashSet<Pos>)> {
+        let (paths, cost) = astar_bag(&self.start, |(p,d)| successors(p, d, &self.walls), |(p,d)| minimum_cost(p, d, &self.end), |(p,_)| *p == self.end)?;
+        let mut poses: HashSet<Pos> = HashSet::new();
+        for path in paths {
+            for (pos, _d) in path {
+                poses.insert(pos);
+            }
+        }
+        Some((cost, poses))
+    }
+}
+
+fn tile(ch: char) -> Result<char, ParseError> {
+    match ch {
+        '.' | '#' | 'S' | 'E' => Ok(ch),
+        _ => Err(ParseError::new("a tile (., #, S or E)", ch.to_string()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Maze, ParseError> {
+    input.parse()
+}
+
+/// Every line which isn't a row of tiles as wide as the first, or if they are all fine, a missing start or end
+pub fn check(input: &str) -> Vec<ParseError> {
+    common::check(Grid::check(input, tile), || parse(input))
+}
+
+/// The minimum cost, and the number of positions on any path with that cost (if the end can be reached)
+pub fn solve(input: &str) -> Result<Option<(u32, usize)>, ParseError> {
+    let maze = parse(input)?;
+    Ok(maze.best_paths().map(|(cost, poses)| (cost, poses.len())))
+}
+
+/// The maze, with every position on a best path drawn in yellow
+pub fn image(input: &str, scale: usize) -> Result<Image, ParseError> {
+    let maze = parse(input)?;
+    let mut image = Image::new(&maze.walls, scale);
+    if let Some((_, poses)) = maze.best_paths() {
+        for pos in poses {
+            image.paint(&pos, Rgb::YELLOW);
+        }
+    }
+    image.paint(&maze.start.0, Rgb::GREEN);
+    image.paint(&maze.end, Rgb::RED);
+    Ok(image)
+}
+
+/// The lowest score (1 per step and 1000 per turn) from the start to the end
+pub fn part1(input: &str) -> Result<Option<u32>, ParseError> {
+    Ok(solve(input)?.map(|(cost, _)| cost))
+}
+
+/// The number of positions which are on at least one of the best paths
+pub fn part2(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(solve(input)?.map(|(_, positions)| positions))
+}
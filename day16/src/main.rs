@@ -1,141 +1,21 @@
-use std::fs;
 use std::env;
-use std::str::FromStr;
-use pathfinding::prelude::astar_bag;
-use std::collections::HashSet;
-
-struct Maze {
-    start: (Pos, Direction),
-    end: Pos,
-    walls: HashSet<Pos>
-}
-
-#[derive(Hash, Eq, PartialEq, Clone, Copy)]
-struct Pos {
-    row: usize,
-    col: usize
-}
-
-#[derive(Hash, Eq, PartialEq, Clone, Copy)]
-enum Direction {
-    North,
-    East,
-    South,
-    West
-}
-
-impl FromStr for Maze {
-    type Err = String;
-
-    fn from_str(text: &str) -> Result<Self, Self::Err> {
-        let mut start = None;
-        let mut end = None;
-        let mut walls = HashSet::new();
-        let mut row = 0;
-        for line in text.lines() {
-            let mut col = 0;
-            for ch in line.chars() {
-                if ch == '#' {
-                    walls.insert(Pos { row, col });
-                } else if ch == 'S' {
-                    start = Some((Pos { row, col }, Direction::East));
-                } else if ch == 'E' {
-                    end = Some(Pos { row, col });
-                }
-                col += 1;
-            }
-            row += 1;
-        }
-        Ok(Self {
-            walls,
-            start: start.expect("Missing start"),
-            end: end.expect("Missing end")
-        })
-    }
-}
-
-impl Pos {
-    fn minimum_cost(&self, _facing: &Direction, end: &Pos) -> u32 {
-        // could add minimum turn cost aswell
-        (self.row.abs_diff(end.row) + self.col.abs_diff(end.col)) as u32
-    }
-  
-    fn successors(&self, facing: &Direction, walls: &HashSet<Pos>) -> Vec<((Pos, Direction), u32)> {
-        let mut v: Vec<((Pos, Direction), u32)> = Vec::new();
-        match facing {
-            Direction::North => {
-                if self.row > 0 {
-                    let new_pos = Pos {
-                        row: self.row - 1,
-                        col: self.col
-                    };
-                    if !walls.contains(&new_pos) {
-                        v.push(((new_pos, *facing), 1));
-                    }
-                }
-                v.push(((*self, Direction::East), 1000));
-                v.push(((*self, Direction::West), 1000));
-            },
-            Direction::West => {
-                if self.col > 0 {
-                    let new_pos = Pos {
-                        row: self.row,
-                        col: self.col - 1
-                    };
-                    if !walls.contains(&new_pos) {
-                        v.push(((new_pos, *facing), 1));
-                    }
-                }
-                v.push(((*self, Direction::North), 1000));
-                v.push(((*self, Direction::South), 1000));
-            },
-            Direction::South => {
-                let new_pos = Pos {
-                    row: self.row + 1,
-                    col: self.col
-                };
-                if !walls.contains(&new_pos) {
-                    v.push(((new_pos, *facing), 1));
-                }
-                v.push(((*self, Direction::East), 1000));
-                v.push(((*self, Direction::West), 1000));
-            },
-            Direction::East => {
-                let new_pos = Pos {
-                    row: self.row,
-                    col: self.col + 1
-                };
-                if !walls.contains(&new_pos) {
-                    v.push(((new_pos, *facing), 1));
-                }
-                v.push(((*self, Direction::North), 1000));
-                v.push(((*self, Direction::South), 1000));
-            }
-        }
-        v
-    }
-  }
+use common::{check_flag, exit_check, log_flags, read_input_or_exit, STDIN};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let maze: Maze = text.parse().unwrap();
-        if let Some((paths, cost)) = astar_bag(&maze.start, |(p,d)| p.successors(d, &maze.walls), |(p,d)| p.minimum_cost(&d, &maze.end), |(p,_)| *p == maze.end) {
+    let (check, args) = check_flag(log_flags(env::args().collect()));
+    if args.len() <= 2 {
+        let filename = args.get(1).map_or(STDIN, |s| s.as_str());
+        let text = read_input_or_exit(filename);
+        if check {
+            exit_check(filename, day16::check(&text));
+        }
+        if let Some((cost, positions)) = day16::solve(&text).unwrap_or_else(|e| e.exit(filename)) {
             println!("Minimum cost: {}", cost);
-            let mut poses: HashSet<Pos> = HashSet::new();
-            for path in paths {
-                for (pos, _d) in path {
-                    poses.insert(pos);
-                }
-            }
-            println!("Number of positions on all shortest paths: {}", poses.len());
+            println!("Number of positions on all shortest paths: {}", positions);
         } else {
             println!("No solution");
         }
     } else {
-        println!("Please provide 1 argument: Filename");
+        println!("Please provide 1 argument: Filename (or - for stdin, the default), with --check to only validate it");
     }
 }
\ No newline at end of file
@@ -0,0 +1,13 @@
+#[test]
+fn test1() {
+    let input = include_str!("../test1.txt");
+    assert_eq!(day16::part1(input).unwrap(), Some(7036));
+    assert_eq!(day16::part2(input).unwrap(), Some(45));
+}
+
+#[test]
+fn test2() {
+    let input = include_str!("../test2.txt");
+    assert_eq!(day16::part1(input).unwrap(), Some(11048));
+    assert_eq!(day16::part2(input).unwrap(), Some(64));
+}
@@ -0,0 +1,235 @@
+//! Day 17: Chronospatial Computer
+
+use std::str::FromStr;
+use common::{ParseError, parse_number, split_sections};
+
+/// A 3-bit computer, with its program, registers and everything it has output so far
+#[derive(Debug, Clone)]
+pub struct Computer {
+    instructions: Vec<u8>,
+    pointer: usize,
+    register_a: usize,
+    register_b: usize,
+    register_c: usize,
+    output: Vec<usize>
+}
+
+impl FromStr for Computer {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let sections = split_sections(text, 2, "registers and a program separated by a blank line")?;
+        let mut registers: Vec<usize> = Vec::new();
+        for (i, line) in sections[0].lines().enumerate() {
+            registers.push(parse_register(line).map_err(|e| e.with_line(sections[0].first_line + i))?);
+        }
+        if registers.len() != 3 {
+            return Err(ParseError::new("3 registers", format!("{} registers", registers.len())).with_line(sections[0].first_line));
+        }
+        let line = sections[1].text;
+        let program_line = sections[1].first_line;
+        let program = line.split(": ").nth(1).ok_or(ParseError::new("Program: instructions", line).with_line(program_line))?;
+        let tokens: Vec<&str> = program.split(",").collect();
+        let instructions: Vec<u8> = tokens.iter().map(|n| parse_number(line, n)).collect::<Result<_, _>>().map_err(|e| e.with_line(program_line))?;
+        check_program(line, &tokens, &instructions).map_err(|e| e.with_line(program_line))?;
+        Ok(Self {
+            register_a: registers[0],
+            register_b: registers[1],
+            register_c: registers[2],
+            instructions,
+            pointer: 0,
+            output: Vec::new()
+        })
+    }
+}
+
+fn parse_register(line: &str) -> Result<usize, ParseError> {
+    let value = line.split(": ").nth(1).ok_or(ParseError::new("Register X: value", line))?;
+    parse_number(line, value)
+}
+
+/// Every opcode is followed by an operand it can use, so that running the program can't fail
+fn check_program(line: &str, tokens: &[&str], instructions: &[u8]) -> Result<(), ParseError> {
+    if !instructions.len().is_multiple_of(2) {
+        return Err(ParseError::new("an operand after every opcode", format!("{} numbers", instructions.len())));
+    }
+    for (i, (token, n)) in tokens.iter().zip(instructions).enumerate() {
+        let opcode = instructions[i - i % 2];
+        let expected = if *n > 7 {
+            "a 3-bit number (0 to 7)"
+        } else if i % 2 == 1 && *n == 7 && Instruction::from(opcode).takes_combo() {
+            "a combo operand (0 to 6)"
+        } else if i % 2 == 1 && !n.is_multiple_of(2) && opcode == 3 {
+            // jumping to an operand would run it as an opcode
+            "an even address to jump to"
+        } else {
+            continue;
+        };
+        return Err(ParseError::at(line, token, expected));
+    }
+    Ok(())
+}
+
+enum Instruction {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv
+}
+
+impl From<u8> for Instruction {
+    fn from(n: u8) -> Self {
+        match n {
+            0 => Self::Adv,
+            1 => Self::Bxl,
+            2 => Self::Bst,
+            3 => Self::Jnz,
+            4 => Self::Bxc,
+            5 => Self::Out,
+            6 => Self::Bdv,
+            7 => Self::Cdv,
+            _ => unreachable!("opcodes are checked when parsing")
+        }
+    }
+}
+
+impl Instruction {
+    fn takes_combo(&self) -> bool {
+        matches!(self, Self::Adv | Self::Bst | Self::Out | Self::Bdv | Self::Cdv)
+    }
+}
+
+impl Computer {
+    /// Run one instruction, returning false once the program has halted
+    pub fn run_next(&mut self) -> bool {
+        if self.pointer >= self.instructions.len() {
+            // halted
+            return false;
+        }
+        let instruction: Instruction = self.instructions[self.pointer].into();
+        self.pointer += 1;
+        let operand = self.instructions[self.pointer];
+        self.pointer += 1;
+        match instruction {
+            Instruction::Adv => self.register_a = self.divide(operand),
+            Instruction::Bxl => self.register_b ^= operand as usize,
+            Instruction::Bst => self.register_b = self.read_combo(operand).rem_euclid(8),
+            Instruction::Jnz => {
+                if self.register_a != 0 {
+                    self.pointer = operand as usize;
+                }
+            },
+            Instruction::Bxc => self.register_b ^= self.register_c, // ignores operand
+            Instruction::Out => self.output.push(self.read_combo(operand).rem_euclid(8)),
+            Instruction::Bdv => self.register_b = self.divide(operand),
+            Instruction::Cdv => self.register_c = self.divide(operand)
+        }
+        self.pointer < self.instructions.len()
+    }
+
+    fn read_combo(&self, operand: u8) -> usize {
+        if operand <= 3 {
+            operand as usize
+        } else if operand == 4 {
+            self.register_a
+        } else if operand == 5 {
+            self.register_b
+        } else if operand == 6 {
+            self.register_c
+        } else {
+            unreachable!("combo operands are checked when parsing")
+        }
+    }
+
+    // register A divided by 2 to the power of the combo operand, which is 0 once that is more than the bits of A
+    fn divide(&self, operand: u8) -> usize {
+        let power = u32::try_from(self.read_combo(operand)).unwrap_or(u32::MAX);
+        self.register_a.checked_shr(power).unwrap_or(0)
+    }
+
+    fn reset(&mut self, register_a: usize) {
+        self.pointer = 0;
+        self.register_a = register_a;
+        self.register_b = 0;
+        self.register_c = 0;
+        self.output.clear();
+    }
+
+    pub fn output(&self) -> &[usize] {
+        &self.output
+    }
+
+    /// A copy of this computer which has run the whole program, starting with register A set to `initial_register_a`
+    pub fn simulate_seed(&self, initial_register_a: usize) -> Self {
+        let mut pc = self.clone();
+        pc.register_a = initial_register_a;
+        while pc.run_next() { }
+        pc
+    }
+}
+
+/// The registers, then a blank line and the program
+pub fn parse(input: &str) -> Result<Computer, ParseError> {
+    input.parse()
+}
+
+/// Every register line which isn't a value, or if they are all fine, whatever else is wrong with the registers or program
+pub fn check(input: &str) -> Vec<ParseError> {
+    let sections = match split_sections(input, 2, "registers and a program separated by a blank line") {
+        Ok(sections) => sections,
+        Err(e) => return vec![e]
+    };
+    common::check(sections[0].check_lines(parse_register), || parse(input))
+}
+
+/// The output of the program, separated by commas
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    let mut pc = parse(input)?;
+    while pc.run_next() { }
+    Ok(pc.output.iter().map(|o| o.to_string()).collect::<Vec<_>>().join(","))
+}
+
+/// The lowest initial value of register A which causes the program to output itself
+pub fn part2(input: &str) -> Result<Option<usize>, ParseError> {
+    let mut pc = parse(input)?;
+    let mut solutions = Vec::new();
+    for seed in 0..1024 {
+        pc.reset(seed);
+        while pc.run_next() { }
+        if pc.output.first() == Some(&(pc.instructions[0] as usize)) {
+            solutions.push(seed);
+        }
+    }
+    let mut magntiude: usize = 1024;
+    for i in 1..pc.instructions.len() {
+        let mut new_solutions = Vec::new();
+        for x in 0..8 {
+            for soln in &solutions {
+                // a program too long to fit its seed in a usize has no solution
+                let Some(seed) = magntiude.checked_mul(x).and_then(|m| m.checked_add(*soln)) else {
+                    continue;
+                };
+                pc.reset(seed);
+                while pc.run_next() { }
+                if pc.output.len() > i && pc.output[i] == pc.instructions[i] as usize {
+                    new_solutions.push(seed);
+                }
+            }
+        }
+        solutions = new_solutions;
+        magntiude = magntiude.saturating_mul(8);
+        //println!("At i={}, {} solutions", i, solutions.len());
+    }
+    Ok(solutions.into_iter().min())
+}
+
+/// The output of the program when register A starts at seed, and the program itself
+pub fn simulate_seed(input: &str, seed: usize) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    let original = parse(input)?;
+    let pc = original.simulate_seed(seed);
+    Ok((pc.output, pc.instructions.iter().map(|i| *i as usize).collect()))
+}
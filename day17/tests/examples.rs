@@ -0,0 +1,41 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day17::part1(input).unwrap(), "4,6,3,5,6,3,5,2,1,0");
+    // this program can't output itself
+    assert_eq!(day17::part2(input).unwrap(), None);
+}
+
+#[test]
+fn test2() {
+    let input = include_str!("../test2.txt");
+    assert_eq!(day17::part1(input).unwrap(), "5,7,3,0");
+    assert_eq!(day17::part2(input).unwrap(), Some(117440));
+}
+
+#[test]
+fn test2_seed() {
+    let input = include_str!("../test2.txt");
+    let (output, expected) = day17::simulate_seed(input, 117440).unwrap();
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_invalid_programs() {
+    let program = |program: &str| format!("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", program);
+    let error = |text: &str| {
+        let error = day17::parse(&program(text)).unwrap_err();
+        assert_eq!(day17::check(&program(text)), vec![error.clone()]);
+        (error.line, error.column, error.expected)
+    };
+    assert_eq!(error("0,1,8,4"), (Some(5), Some(14), "a 3-bit number (0 to 7)".to_string()));
+    assert_eq!(error("0,1,5,7"), (Some(5), Some(16), "a combo operand (0 to 6)".to_string()));
+    assert_eq!(error("0,1,3,1"), (Some(5), Some(16), "an even address to jump to".to_string()));
+    assert_eq!(error("0,1,5"), (Some(5), None, "an operand after every opcode".to_string()));
+    // a literal operand of 7 is fine
+    assert_eq!(day17::part1(&program("1,7,5,5")).unwrap(), "7");
+    // dividing by more than 2^63 gives 0 rather than overflowing
+    assert_eq!(day17::part1("Register A: 729\nRegister B: 100\nRegister C: 0\n\nProgram: 0,5,5,4\n").unwrap(), "0");
+    // a program which outputs nothing can't output itself
+    assert_eq!(day17::part2(&program("1,7")).unwrap(), None);
+}
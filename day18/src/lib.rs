@@ -0,0 +1,159 @@
+//! Day 18: RAM Run
+
+use pathfinding::prelude::bfs;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use grid::{Grid, Image, Pos, Rgb, Simulation, Size};
+use common::{ParseError, check_lines, parse_number};
+
+/// A square memory space, with which positions have been corrupted
+pub struct Memory {
+    corrupted: Grid<bool>,
+    size: usize
+}
+
+impl Display for Memory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.corrupted.map(|c| if *c { '#' } else { '.' }))
+    }
+}
+
+impl Memory {
+    pub fn new(size: usize) -> Self {
+        Self {
+            corrupted: Grid::new(Size::new(size, size), false),
+            size
+        }
+    }
+
+    pub fn corrupt(&mut self, pos: &Pos) {
+        self.corrupted.set(pos, true);
+    }
+
+    fn available_adjacent_to(&self, pos: &Pos) -> Vec<Pos> {
+        self.corrupted.adjacent(pos).into_iter().filter(|p| !self.corrupted[*p]).collect()
+    }
+
+    /// The number of steps from the top left to the bottom right, if there is still a path
+    pub fn shortest_path(&self) -> Option<usize> {
+        Some(self.path()?.len() - 1)
+    }
+
+    /// Every position along a shortest path from the top left to the bottom right, if there is still one
+    pub fn path(&self) -> Option<Vec<Pos>> {
+        let start = Pos {
+            row: 0,
+            col: 0
+        };
+        let end = Pos {
+            row: self.size - 1,
+            col: self.size - 1
+        };
+        bfs(&start, |p| self.available_adjacent_to(p), |p| *p == end)
+    }
+}
+
+fn parse_pos(line: &str) -> Result<Pos, ParseError> {
+    // 0,4 => row:4, col:0
+    let numbers: Vec<usize> = line.split(",").map(|s| parse_number(line, s)).collect::<Result<_, _>>()?;
+    if numbers.len() != 2 {
+        return Err(ParseError::new("2 numbers separated by ,", line));
+    }
+    Ok(Pos::new(numbers[1], numbers[0]))
+}
+
+/// The positions where bytes fall (one per line, as col,row), and the size of the memory space (7 for test, 71 for input)
+pub fn parse(input: &str) -> Result<(Vec<Pos>, usize), ParseError> {
+    let bytes: Vec<Pos> = input.lines().enumerate().map(|(i, l)| parse_pos(l).map_err(|e| e.with_line(i + 1))).collect::<Result<_, _>>()?;
+    if bytes.is_empty() {
+        return Err(ParseError::new("at least 1 byte", ""));
+    }
+    let size = bytes.iter().map(|b| b.row).max().unwrap().max(bytes.iter().map(|b| b.col).max().unwrap()) + 1;
+    Ok((bytes, size))
+}
+
+/// Every line which isn't a position, or if they are all fine, that there are none
+pub fn check(input: &str) -> Vec<ParseError> {
+    common::check(check_lines(input, parse_pos), || parse(input))
+}
+
+/// The bytes falling into memory one at a time, with the shortest path (while there is one) drawn as O
+pub struct FallingBytes {
+    memory: Memory,
+    bytes: Vec<Pos>,
+    next: usize
+}
+
+impl FallingBytes {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (bytes, size) = parse(input)?;
+        Ok(Self {
+            memory: Memory::new(size),
+            bytes,
+            next: 0
+        })
+    }
+}
+
+impl Simulation for FallingBytes {
+    fn step(&mut self) -> bool {
+        let Some(byte) = self.bytes.get(self.next) else {
+            return false;
+        };
+        self.memory.corrupt(byte);
+        self.next += 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        let mut chars = self.memory.corrupted.map(|c| if *c { '#' } else { '.' });
+        for pos in self.memory.path().unwrap_or_default() {
+            chars[pos] = 'O';
+        }
+        chars.to_string()
+    }
+
+    fn image(&self, scale: usize) -> Image {
+        let mut image = Image::new(&self.memory.corrupted, scale);
+        for pos in self.memory.path().unwrap_or_default() {
+            image.paint(&pos, Rgb::YELLOW);
+        }
+        image
+    }
+
+    fn status(&self) -> String {
+        let path = match self.memory.shortest_path() {
+            Some(steps) => format!("shortest path {} steps", steps),
+            None => "no path".to_string()
+        };
+        format!("Byte {}/{}, {}", self.next, self.bytes.len(), path)
+    }
+}
+
+/// The shortest path once the first kilobyte (or 12 bytes of the test) has fallen
+pub fn part1(input: &str) -> Result<Option<usize>, ParseError> {
+    let (bytes, size) = parse(input)?;
+    let mut m = Memory::new(size);
+    let bytes_to_sim = if size < 10 {
+        12 //test
+    } else {
+        1024 // not test
+    };
+    for byte in bytes.iter().take(bytes_to_sim) {
+        m.corrupt(byte);
+    }
+    Ok(m.shortest_path())
+}
+
+/// The first byte which blocks the path, as "col,row"
+pub fn part2(input: &str) -> Result<Option<String>, ParseError> {
+    let (bytes, size) = parse(input)?;
+    let mut m = Memory::new(size);
+    for byte in bytes {
+        m.corrupt(&byte);
+        if m.shortest_path().is_none() {
+            return Ok(Some(format!("{},{}", byte.col, byte.row)));
+        }
+    }
+    Ok(None)
+}
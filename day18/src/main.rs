@@ -1,163 +1,21 @@
-use std::fs;
 use std::env;
-use std::str::FromStr;
-use pathfinding::prelude::bfs;
-use std::fmt::Display;
-use std::fmt::Formatter;
-
-struct Memory {
-    corrupted: Vec<Vec<bool>>,
-    size: usize
-}
-
-impl Display for Memory {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        for row in 0..self.size {
-            for col in 0..self.size {
-                if self.corrupted[row][col] {
-                    write!(f, "#")?;
-                } else {
-                    write!(f, ".")?;
-                }
-            }
-            writeln!(f, "")?;
-        }
-        Ok(())
-    }
-}
-
-impl Memory {
-    fn new(size: usize) -> Self {
-        let mut corrupted = Vec::new();
-        for _i in 0..size {
-            let mut row = Vec::new();
-            for _j in 0..size {
-                row.push(false);
-            }
-            corrupted.push(row);
-        }
-        Self {
-            corrupted, size
-        }
-    }
-
-    fn corrupt(&mut self, pos: &Pos) {
-        self.corrupted[pos.row][pos.col] = true;
-    }
-
-    fn is_available(&self, pos: &Pos) -> bool {
-        if pos.row >= self.size || pos.col >= self.size {
-            false
-        } else {
-            !self.corrupted[pos.row][pos.col]
-        }
-    }
-
-    fn available_adjacent_to(&self, pos: &Pos) -> Vec<Pos> {
-        let mut v = Vec::new();
-        if pos.row > 0 {
-            let p = Pos {
-                row: pos.row - 1,
-                col: pos.col
-            };
-            if self.is_available(&p) {
-                v.push(p);
-            }
-        }
-        if pos.col > 0 {
-            let p = Pos {
-                row: pos.row,
-                col: pos.col - 1
-            };
-            if self.is_available(&p) {
-                v.push(p);
-            }
-        }
-        let p = Pos {
-            row: pos.row + 1,
-            col: pos.col
-        };
-        if self.is_available(&p) {
-            v.push(p);
-        }
-        let p = Pos {
-            row: pos.row,
-            col: pos.col + 1
-        };
-        if self.is_available(&p) {
-            v.push(p);
-        }
-        v
-    }
-}
-
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-struct Pos {
-    row: usize,
-    col: usize
-}
-
-impl FromStr for Pos {
-    type Err = String;
-
-    fn from_str(line: &str) -> Result<Self, Self::Err> {
-        // 0,4 => row:4, col:0
-        let numbers: Vec<usize> = line.split(",").map(|s| s.parse().unwrap()).collect();
-        if numbers.len() != 2 {
-            panic!("Invalid number count");
-        }
-        Ok(Self {
-            row: numbers[1],
-            col: numbers[0]
-        })
-    }
-}
+use common::{check_flag, exit_check, log_flags, read_input_or_exit, STDIN};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let bytes: Vec<Pos> = text.lines().map(|s| s.parse().unwrap()).collect();
-        let size = bytes.iter().map(|b| b.row).max().unwrap().max(bytes.iter().map(|b| b.col).max().unwrap()) + 1;
-        let mut m = Memory::new(size); // 7 for test, 71 for input
-        //part1
-        let bytes_to_sim = if size < 10 {
-            12 //test
+    let (check, args) = check_flag(log_flags(env::args().collect()));
+    if args.len() <= 2 {
+        let filename = args.get(1).map_or(STDIN, |s| s.as_str());
+        let text = read_input_or_exit(filename);
+        if check {
+            exit_check(filename, day18::check(&text));
+        }
+        println!("Part1: {}", day18::part1(&text).unwrap_or_else(|e| e.exit(filename)).expect("no path found"));
+        if let Some(byte) = day18::part2(&text).unwrap_or_else(|e| e.exit(filename)) {
+            println!("Part2: {}", byte);
         } else {
-            1024 // not test
-        };
-        for i in 0..bytes_to_sim {
-            if i == bytes.len() {
-                println!("Ran out of bytes");
-                break;
-            }
-            m.corrupt(&bytes[i]);
-        }
-        let start = Pos {
-            row: 0,
-            col: 0
-        };
-        let end = Pos {
-            row: m.size - 1,
-            col: m.size - 1
-        };
-        let result = bfs(&start, |p| m.available_adjacent_to(p), |p| *p == end);
-        println!("{}", m);
-        println!("Part1: {}", result.expect("no path found").len() - 1);
-        //part2
-        m = Memory::new(size);
-        for byte in bytes {
-            m.corrupt(&byte);
-            if bfs(&start, |p| m.available_adjacent_to(p), |p| *p == end).is_none() {
-                // this is the first byte that blocked it
-                println!("part2: {:?} (remember to reverse into (col, row) for answer)", byte);
-                return;
-            }
+            println!("sim'd all bytes");
         }
-        println!("sim'd all bytes")
     } else {
-        println!("Please provide 1 argument: Filename");
+        println!("Please provide 1 argument: Filename (or - for stdin, the default), with --check to only validate it");
     }
 }
\ No newline at end of file
@@ -0,0 +1,6 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day18::part1(input).unwrap(), Some(22));
+    assert_eq!(day18::part2(input).unwrap(), Some("6,1".to_string()));
+}
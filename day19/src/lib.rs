@@ -0,0 +1,122 @@
+//! Day 19: Linen Layout
+
+use common::{Cache, ParseError, split_sections, verbose};
+
+// enum Color {
+//     White,
+//     Blue,
+//     Black,
+//     Red,
+//     Green
+// }
+
+// impl Color {
+//     fn from_char(ch: char) -> Self {
+//         match ch {
+//             'w' => Self::White,
+//             'u' => Self::Blue,
+//             'b' => Self::Black,
+//             'r' => Self::Red,
+//             'g' => Self::Green,
+//             _ => panic!("Invalid color {}", ch)
+//         }
+//     }
+// }
+
+// struct Towel {
+//     stripes: Vec<Color>
+// }
+
+// impl FromStr for Towel {
+//     type Err = String;
+
+//     fn from_str(line: &str) -> Result<Self, Self::Err> {
+//         let stripes: Vec<_> = line.chars().map(|ch| Color::from_char(ch)).collect();
+//         Ok(Self {
+//             stripes
+//         })
+//     }
+// }
+
+// impl Towel {
+//     fn can_be_made_from(&self, subs: &Vec<Towel>) -> bool {
+//         t odo!()
+//     }
+// }
+
+/// The available towels (separated by commas), then a blank line and one design per line
+pub fn parse(text: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    let sections = split_sections(text, 2, "towels and designs separated by a blank line")?;
+    let mut available = Vec::new();
+    for (i, line) in sections[0].lines().enumerate() {
+        available.extend(towels(line).map_err(|e| e.with_line(sections[0].first_line + i))?);
+    }
+    let designs = sections[1].lines().enumerate()
+        .map(|(i, line)| stripes(line, line).map_err(|e| e.with_line(sections[1].first_line + i)))
+        .collect::<Result<_, _>>()?;
+    Ok((available, designs))
+}
+
+fn towels(line: &str) -> Result<Vec<&str>, ParseError> {
+    line.split(", ").map(|towel| stripes(line, towel)).collect()
+}
+
+/// A towel or design, which should be a slice of `line` so that the column of an error can be found
+fn stripes<'a>(line: &str, token: &'a str) -> Result<&'a str, ParseError> {
+    match token.char_indices().find(|(_, ch)| !"wubrg".contains(*ch)) {
+        _ if token.is_empty() => Err(ParseError::at(line, token, "stripes (w, u, b, r or g)")),
+        Some((i, ch)) => Err(ParseError::at(line, &token[i..(i + ch.len_utf8())], "a stripe (w, u, b, r or g)")),
+        None => Ok(token)
+    }
+}
+
+/// Every line which isn't a list of towels (in the first section) or a design (in the second)
+pub fn check(text: &str) -> Vec<ParseError> {
+    let sections = match split_sections(text, 2, "towels and designs separated by a blank line") {
+        Ok(sections) => sections,
+        Err(e) => return vec![e]
+    };
+    let mut errors = sections[0].check_lines(towels);
+    errors.extend(sections[1].check_lines(|line| stripes(line, line)));
+    errors
+}
+
+/// The number of ways each design can be made from the available towels
+pub fn ways_to_make_designs(text: &str) -> Result<Vec<usize>, ParseError> {
+    let (available, designs) = parse(text)?;
+    let mut result_cache = Cache::new();
+    let ways = designs.iter().map(|d| number_of_ways_to_make(&mut result_cache, d, &available)).collect();
+    verbose!("Design cache: {}", result_cache.stats());
+    Ok(ways)
+}
+
+/// The number of designs which can be made
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(ways_to_make_designs(input)?.iter().filter(|n| **n > 0).count())
+}
+
+/// The total number of ways to make every design
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(ways_to_make_designs(input)?.iter().sum())
+}
+
+/// The number of ways `target` can be made from the available towels, remembering the result for each remaining part of it in `result_cache`
+pub fn number_of_ways_to_make<'a>(result_cache: &mut Cache<&'a str, usize>, target: &'a str, available: &Vec<&str>) -> usize {
+    if let Some(result) = result_cache.get(&target) {
+        *result
+    } else {
+        let mut count = 0;
+        for a in available {
+            if target.starts_with(a) {
+                let remaining = &target[a.len()..target.len()];
+                if remaining.is_empty() {
+                    count += 1;
+                } else {
+                    count += number_of_ways_to_make(result_cache, remaining, available);
+                }
+            }
+        }
+        result_cache.insert(target, count);
+        count
+    }
+}
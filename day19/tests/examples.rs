@@ -0,0 +1,6 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day19::part1(input).unwrap(), 6);
+    assert_eq!(day19::part2(input).unwrap(), 16);
+}
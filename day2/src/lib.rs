@@ -0,0 +1,94 @@
+//! Day 2: Red-Nosed Reports
+
+use std::str::FromStr;
+use common::{ParseError, check_lines, parse_lines, parse_number};
+
+/// The levels of one reactor report
+#[derive(Debug)]
+pub struct Report {
+    levels: Vec<usize>
+}
+
+impl FromStr for Report {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let levels: Vec<usize> = line.split(" ").map(|n| parse_number(line, n)).collect::<Result<_, _>>()?;
+        Ok(Self{ levels })
+    }
+}
+
+impl Report {
+    pub fn new(levels: Vec<usize>) -> Self {
+        Self {
+            levels
+        }
+    }
+
+    fn reversed(&self) -> Self {
+        Self {
+            levels: self.levels.iter().rev().cloned().collect::<Vec<usize>>()
+        }
+    }
+
+    /// Whether the levels only increase (or only decrease) by 1-3 at a time
+    pub fn is_safe(&self) -> bool {
+        self.is_gradually_ascending() || self.reversed().is_gradually_ascending()
+    }
+
+    fn is_gradually_ascending(&self) -> bool {
+        let mut last = self.levels[0];
+        for i in 1..self.levels.len() {
+            let delta = self.levels[i] as isize - last as isize;
+            if !(1..=3).contains(&delta) {
+                return false;
+            }
+            last = self.levels[i];
+        }
+        true
+    }
+
+    /// Whether the report is safe after removing at most one level
+    pub fn is_safe_with_dampener(&self) -> bool {
+        if self.is_safe() {
+            return true;
+        }
+        for i in 0..self.levels.len() {
+            let mut subset = Vec::new();
+            for j in 0..self.levels.len() {
+                if i != j {
+                    subset.push(self.levels[j]);
+                }
+            }
+            let sub_report = Self {
+                levels: subset
+            };
+            if sub_report.is_safe() {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// One report per line
+pub fn parse(input: &str) -> Result<Vec<Report>, ParseError> {
+    parse_lines(input)
+}
+
+/// Every line which isn't a report
+pub fn check(input: &str) -> Vec<ParseError> {
+    check_lines(input, Report::from_str)
+}
+
+/// The number of safe reports
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let reports = parse(input)?;
+    Ok(reports.iter().filter(|r| r.is_safe()).count())
+}
+
+/// The number of reports which are safe with the problem dampener
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let reports = parse(input)?;
+    Ok(reports.iter().filter(|r| r.is_safe_with_dampener()).count())
+}
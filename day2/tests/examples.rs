@@ -0,0 +1,6 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day2::part1(input).unwrap(), 2);
+    assert_eq!(day2::part2(input).unwrap(), 4);
+}
@@ -0,0 +1,23 @@
+use proptest::prelude::*;
+use day2::Report;
+
+// mostly small steps, so that plenty of reports are safe (or nearly)
+fn report() -> impl Strategy<Value = Report> {
+    (1..20usize, prop::collection::vec(-4..=4isize, 0..10)).prop_map(|(start, deltas)| {
+        let mut levels = vec![start];
+        for delta in deltas {
+            let last = *levels.last().unwrap() as isize;
+            levels.push((last + delta).max(0) as usize);
+        }
+        Report::new(levels)
+    })
+}
+
+proptest! {
+    #[test]
+    fn safe_is_safe_with_dampener(report in report()) {
+        if report.is_safe() {
+            prop_assert!(report.is_safe_with_dampener());
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day20::Race;
+
+// with the cheat lengths and threshold of each part
+fn cheat_paths(c: &mut Criterion) {
+    let race: Race = include_str!("../input.txt").parse().unwrap();
+    c.bench_function("day20 cheat_paths 2 moves", |b| b.iter(|| race.cheat_paths(2, 100)));
+    c.bench_function("day20 cheat_paths 20 moves", |b| b.iter(|| race.cheat_paths(20, 100)));
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = cheat_paths
+}
+criterion_main!(benches);
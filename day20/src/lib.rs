@@ -0,0 +1,153 @@
+//! Day 20: Race Condition
+
+use std::str::FromStr;
+use pathfinding::prelude::astar;
+use std::collections::HashMap;
+use grid::{Grid, Image, Pos, Rgb};
+use common::{ParseError, Progress};
+
+/// The race track, a single path from start to end through the walls
+pub struct Race {
+    start: Pos,
+    end: Pos,
+    walls: Grid<bool>
+}
+
+impl FromStr for Race {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let chars = Grid::parse(text, tile)?;
+        let start = chars.position(|ch| *ch == 'S');
+        let end = chars.position(|ch| *ch == 'E');
+        Ok(Self {
+            walls: chars.map(|ch| *ch == '#'),
+            start: start.ok_or(ParseError::new("a start (S)", ""))?,
+            end: end.ok_or(ParseError::new("an end (E)", ""))?
+        })
+    }
+}
+
+/// Where a cheat leaves and rejoins the track
+#[derive(Clone, Hash, Eq, PartialEq, Debug)]
+pub struct Cheat {
+    start: Pos,
+    end: Pos
+}
+
+impl Race {
+    // all poses we go through on the no cheat path, from start to end
+    fn path(&self) -> Vec<Pos> {
+        let (path, _) = astar(
+            &self.start,
+            |p| self.walls.adjacent(p).into_iter().filter(|p| !self.walls[*p]).map(|p| (p, 1)).collect::<Vec<(Pos, u32)>>(),
+            |p| p.manhattan_distance(&self.end) as u32,
+            |p| *p == self.end
+        ).expect("No solution");
+        path
+    }
+
+    fn no_cheat_path(&self) -> usize {
+        self.path().len() - 1
+    }
+
+    /// Every cheat of up to `moves` steps through walls which saves at least `threshold` picoseconds, and how many it saves
+    pub fn cheat_paths(&self, moves: u32, threshold: u32) -> HashMap<Cheat, usize> {
+        let no_cheat_path = self.path();
+        // go through each pos and look for paths through walls which are less than 20 long
+        let mut cheat_paths = HashMap::new();
+        let mut progress = Progress::new("Cheat starts", no_cheat_path.len());
+        for s in 0..no_cheat_path.len() {
+            progress.set(s);
+            for e in (s+1)..no_cheat_path.len() {
+                let start = &no_cheat_path[s];
+                let end = &no_cheat_path[e];
+                let minimum_distance = start.manhattan_distance(end) as u32;
+                if minimum_distance > moves {
+                    continue; // cheat wont be long enough
+                }
+                let no_cheat_length = e as u32 - s as u32;
+                if no_cheat_length == minimum_distance {
+                    continue; // cheat cant possibly help
+                }
+                if let Some((_, cheat_length)) = astar(
+                    start,
+                    |p| self.walls.adjacent(p).into_iter().map(|p| (p, 1)).collect::<Vec<(Pos, u32)>>(),
+                    |p| p.manhattan_distance(end) as u32,
+                    |p| *p == *end
+                ) {
+                    let pico_saved = no_cheat_length - cheat_length;
+                    if cheat_length <= moves && cheat_length < no_cheat_length && pico_saved >= threshold {
+                        let cheat = Cheat {
+                            start: *start,
+                            end: *end
+                        };
+                        cheat_paths.insert(cheat, pico_saved as usize);
+                    }
+                }
+            }
+        }
+        cheat_paths
+    }
+}
+
+fn tile(ch: char) -> Result<char, ParseError> {
+    match ch {
+        '.' | '#' | 'S' | 'E' => Ok(ch),
+        _ => Err(ParseError::new("a tile (., #, S or E)", ch.to_string()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Race, ParseError> {
+    input.parse()
+}
+
+/// Every line which isn't a row of tiles as wide as the first, or if they are all fine, a missing start or end
+pub fn check(input: &str) -> Vec<ParseError> {
+    common::check(Grid::check(input, tile), || parse(input))
+}
+
+/// The picoseconds it takes to finish the race without cheating
+pub fn no_cheat_path(input: &str) -> Result<usize, ParseError> {
+    let race = parse(input)?;
+    Ok(race.no_cheat_path())
+}
+
+/// The race track, with the path (without cheating) drawn in yellow
+pub fn image(input: &str, scale: usize) -> Result<Image, ParseError> {
+    let race = parse(input)?;
+    let mut image = Image::new(&race.walls, scale);
+    for pos in race.path() {
+        image.paint(&pos, Rgb::YELLOW);
+    }
+    image.paint(&race.start, Rgb::GREEN);
+    image.paint(&race.end, Rgb::RED);
+    Ok(image)
+}
+
+/// How many cheats save each number of picoseconds, ordered by picoseconds saved
+pub fn cheats_by_saving(input: &str, moves: u32, threshold: u32) -> Result<Vec<(usize, usize)>, ParseError> {
+    let race = parse(input)?;
+    let result = race.cheat_paths(moves, threshold);
+    let mut count_by_saved = HashMap::new();
+    for (_, pico) in result {
+        if let Some(existing) = count_by_saved.get(&pico) {
+            count_by_saved.insert(pico, existing + 1);
+        } else {
+            count_by_saved.insert(pico, 1);
+        }
+    }
+    let mut cbs_vec: Vec<_> = count_by_saved.into_iter().collect();
+    cbs_vec.sort_by_key(|a| a.0);
+    Ok(cbs_vec)
+}
+
+/// The number of 2 picosecond cheats which save at least 100 picoseconds
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(cheats_by_saving(input, 2, 100)?.iter().map(|(_, count)| count).sum())
+}
+
+/// The number of 20 picosecond cheats which save at least 100 picoseconds
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(cheats_by_saving(input, 20, 100)?.iter().map(|(_, count)| count).sum())
+}
@@ -0,0 +1,25 @@
+// no cheats in the example save the 100 picoseconds required by part1/part2, so check the savings tables instead
+
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day20::no_cheat_path(input).unwrap(), 84);
+    assert_eq!(day20::part1(input).unwrap(), 0);
+    assert_eq!(day20::part2(input).unwrap(), 0);
+}
+
+#[test]
+fn test_2_moves() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day20::cheats_by_saving(input, 2, 1).unwrap(), vec![
+        (2, 14), (4, 14), (6, 2), (8, 4), (10, 2), (12, 3), (20, 1), (36, 1), (38, 1), (40, 1), (64, 1)
+    ]);
+}
+
+#[test]
+fn test_20_moves() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day20::cheats_by_saving(input, 20, 50).unwrap(), vec![
+        (50, 32), (52, 31), (54, 29), (56, 39), (58, 25), (60, 23), (62, 20), (64, 19), (66, 12), (68, 14), (70, 12), (72, 22), (74, 4), (76, 3)
+    ]);
+}
@@ -0,0 +1,10 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// every code through the keypads of part 1 (part 2 doesn't finish yet)
+fn shortest_path_to_code(c: &mut Criterion) {
+    let input = include_str!("../input.txt");
+    c.bench_function("day21 shortest_path_to_code", |b| b.iter(|| day21::shortest_paths(input).unwrap()));
+}
+
+criterion_group!(benches, shortest_path_to_code);
+criterion_main!(benches);
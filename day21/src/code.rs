@@ -1,18 +1,22 @@
 use std::str::FromStr;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use common::ParseError;
 use crate::keypad::Key;
 use crate::numeric::NumericKey;
 
+/// The keys to press, in order
 pub struct Code<K: Key> {
     pub keys: Vec<K>
 }
 
 impl<K: Key> FromStr for Code<K> {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
-        let keys = line.chars().map(|c| K::from_char(c)).collect();
+        let keys = line.chars().enumerate().map(|(i, c)| {
+            K::from_char(c).ok_or(ParseError::new("a key", c.to_string()).with_column(i + 1))
+        }).collect::<Result<_, _>>()?;
         Ok(Self {
             keys
         })
@@ -29,6 +33,7 @@ impl<K: Key> Display for Code<K> {
 }
 
 impl Code<NumericKey> {
+    /// The number made by the digits of the code, ignoring A
     pub fn numeric_part(&self) -> usize {
         let mut n = 0;
         for k in &self.keys {
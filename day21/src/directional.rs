@@ -5,8 +5,9 @@ use crate::keypad::Key;
 // +---+---+---+
 // | < | v | > |
 // +---+---+---+
-#[derive(Clone, Hash, Eq, PartialEq, Copy, Debug)]
+#[derive(Clone, Hash, Eq, PartialEq, Copy, Debug, Default)]
 pub enum DirectionalKey {
+    #[default]
     Activate,
     Move(Direction)
 }
@@ -29,21 +30,15 @@ impl DirectionalKey {
     ];
 }
 
-impl Default for DirectionalKey {
-    fn default() -> Self {
-        Self::Activate
-    }
-}
-
 impl Key for DirectionalKey {
-    fn from_char(c: char) -> Self {
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            'A' => Self::Activate,
-            '^' => Self::Move(Direction::Up),
-            '<' => Self::Move(Direction::Left),
-            '>' => Self::Move(Direction::Right),
-            'v' => Self::Move(Direction::Down),
-            _ => panic!("Invalid directional key: {}", c)
+            'A' => Some(Self::Activate),
+            '^' => Some(Self::Move(Direction::Up)),
+            '<' => Some(Self::Move(Direction::Left)),
+            '>' => Some(Self::Move(Direction::Right)),
+            'v' => Some(Self::Move(Direction::Down)),
+            _ => None
         }
     }
 
@@ -4,7 +4,7 @@ use std::hash::Hash;
 use std::marker::PhantomData;
 
 pub trait Key : Sized + Default + Clone + Copy + Hash + Eq + PartialEq {
-    fn from_char(c: char) -> Self;
+    fn from_char(c: char) -> Option<Self>;
     fn to_char(&self) -> char;
     fn key_above(&self) -> Option<Self>;
     fn key_below(&self) -> Option<Self>;
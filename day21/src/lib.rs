@@ -0,0 +1,143 @@
+//! Day 21: Keypad Conundrum
+
+use std::str::FromStr;
+use pathfinding::prelude::astar;
+use common::{Cache, ParseError, check_lines, debug, parse_lines, verbose};
+
+use crate::keypad::{Keypad, FinalKeypad, RobotKeypad};
+
+pub use crate::keypad::Key;
+pub use crate::code::Code;
+pub use crate::numeric::NumericKey;
+
+mod keypad;
+mod code;
+mod numeric;
+mod directional;
+
+/// One door code per line
+pub fn parse(input: &str) -> Result<Vec<Code<NumericKey>>, ParseError> {
+    parse_lines(input)
+}
+
+/// Every line which isn't a door code
+pub fn check(input: &str) -> Vec<ParseError> {
+    check_lines(input, Code::<NumericKey>::from_str)
+}
+
+/// The sum of the complexities of the codes, typed through 2 robots (and the numeric keypad's robot)
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let mut sum = 0;
+    for code in parse(input)? {
+        sum += code.numeric_part() * shortest_path_via_2_robots(&code);
+    }
+    Ok(sum)
+}
+
+/// The fewest button presses needed to type each code through the keypads of part 1
+pub fn shortest_paths(input: &str) -> Result<Vec<usize>, ParseError> {
+    Ok(parse(input)?.iter().map(shortest_path_via_2_robots).collect())
+}
+
+fn shortest_path_via_2_robots(code: &Code<NumericKey>) -> usize {
+    let keypad = RobotKeypad::controlling(
+        RobotKeypad::<FinalKeypad, NumericKey>::controlling(
+            FinalKeypad::new()
+        )
+    );
+    shortest_path_to_code(keypad, code)
+}
+
+/// The sum of the complexities of the codes, typed through 25 robots (and the numeric keypad's robot)
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let mut sum = 0;
+    for code in parse(input)? {
+        let keypad = RobotKeypad::controlling(
+            RobotKeypad::controlling(
+                RobotKeypad::controlling(
+                    RobotKeypad::controlling(
+                        RobotKeypad::controlling(
+                            RobotKeypad::controlling(
+                                RobotKeypad::controlling(
+                                    RobotKeypad::controlling(
+                                        RobotKeypad::controlling(
+                                            RobotKeypad::controlling(
+                                                RobotKeypad::controlling(
+                                                    RobotKeypad::controlling(
+                                                        RobotKeypad::controlling(
+                                                            RobotKeypad::controlling(
+                                                                RobotKeypad::controlling(
+                                                                    RobotKeypad::controlling(
+                                                                        RobotKeypad::controlling(
+                                                                            RobotKeypad::controlling(
+                                                                                RobotKeypad::controlling(
+                                                                                    RobotKeypad::controlling(
+                                                                                        RobotKeypad::controlling(
+                                                                                            RobotKeypad::controlling(
+                                                                                                RobotKeypad::controlling(
+                                                                                                    RobotKeypad::controlling(
+                                                                                                        RobotKeypad::<FinalKeypad, NumericKey>::controlling(
+                                                                                                            FinalKeypad::new()
+                                                                                                        )
+                                                                                                    )
+                                                                                                )
+                                                                                            )
+                                                                                        )
+                                                                                    )
+                                                                                )
+                                                                            )
+                                                                        )
+                                                                    )
+                                                                )
+                                                            )
+                                                        )
+                                                    )
+                                                )
+                                            )
+                                        )
+                                    )
+                                )
+                            )
+                        )
+                    )
+                )
+            )
+        );
+        let shortest = shortest_path_to_code(keypad, &code);
+        let numeric_part = code.numeric_part();
+        let complexity = numeric_part * shortest;
+        verbose!("Code: {}, Shortest: {}, Complexity: {}", code, shortest, complexity);
+        sum += complexity;
+    }
+    Ok(sum)
+}
+
+fn shortest_path_to_code<KP: Keypad<K>, K: Key>(start: RobotKeypad<KP, K>, code: &Code<NumericKey>) -> usize {
+    let mut shortest = 0;
+    let mut state = start;
+    let mut cache = Cache::new();
+    for nk in &code.keys {
+        debug!("Finding path to {:?}", nk);
+        let (final_state, length) = shortest_path_to_key(&state, nk, &mut cache);
+        debug!("Shortest: {}", length);
+        state = final_state;
+        shortest += length;
+        shortest += 1; // press activate
+    }
+    debug!("Path cache: {}", cache.stats());
+    shortest
+}
+
+type PathCache<KP, K> = Cache<(RobotKeypad<KP, K>, NumericKey), (RobotKeypad<KP, K>, usize)>;
+
+fn shortest_path_to_key<KP: Keypad<K>, K: Key>(start: &RobotKeypad<KP, K>, key: &NumericKey, cache: &mut PathCache<KP, K>) -> (RobotKeypad<KP, K>, usize) {
+    let cache_key = (start.clone(), *key);
+    if let Some(cached) = cache.get(&cache_key) {
+        cached.clone()
+    } else {
+        let (result, length) = astar(start, |kp| kp.successors(), |kp| kp.minimum_moves_to_final_key(key), |kp| kp.ready_for_final_key(key)).expect("No solution");
+        let final_state = result.into_iter().last().unwrap();
+        cache.insert(cache_key, (final_state.clone(), length));
+        (final_state, length)
+    }
+}
\ No newline at end of file
@@ -9,35 +9,26 @@ use crate::keypad::Key;
 // +---+---+---+
 //     | 0 | A |
 //     +---+---+
-#[derive(Clone, Hash, Eq, PartialEq, Copy, Debug)]
+#[derive(Clone, Hash, Eq, PartialEq, Copy, Debug, Default)]
 pub enum NumericKey {
+    #[default]
     Activate,
     Digit(u8)
 }
 
-impl Default for NumericKey {
-    fn default() -> Self {
-        Self::Activate
-    }
-}
-
 impl Key for NumericKey {
-    fn from_char(c: char) -> Self {
+    fn from_char(c: char) -> Option<Self> {
         if c == 'A' {
-            Self::Activate
+            Some(Self::Activate)
         } else {
-            let d = c as u8 - '0' as u8;
-            if d > 9 {
-                panic!("Invalid numeric key: {}", c);
-            }
-            Self::Digit(d)
+            c.to_digit(10).map(|d| Self::Digit(d as u8))
         }
     }
 
     fn to_char(&self) -> char {
         match self {
             Self::Activate => 'A',
-            Self::Digit(d) => (d + '0' as u8) as char
+            Self::Digit(d) => (d + b'0') as char
         }
     }
 
@@ -0,0 +1,13 @@
+#[test]
+#[ignore = "day 21 is unsolved, part1 gives 127900"]
+fn test_part1() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day21::part1(input).unwrap(), 126384);
+}
+
+#[test]
+#[ignore = "day 21 is unsolved, part2 doesn't finish"]
+fn test_part2() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day21::part2(input).unwrap(), 154115708116294);
+}
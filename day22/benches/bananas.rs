@@ -0,0 +1,14 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// the whole search of part 2 through all 19^4 sequences, over the 4 buyers of the example since the input's are far too slow to repeat
+fn best_bananas(c: &mut Criterion) {
+    let input = include_str!("../test2.txt");
+    c.bench_function("day22 best_bananas", |b| b.iter(|| day22::best_bananas(input).unwrap()));
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = best_bananas
+}
+criterion_main!(benches);
@@ -0,0 +1,124 @@
+//! Day 22: Monkey Market
+
+use std::ops::BitXor;
+use common::{ParseError, Progress, check_lines, parse_number};
+
+/// A buyer's secret number, with every price it has given so far and the changes between them
+#[derive(Debug)]
+pub struct Secret {
+    number: usize,
+    prices: Vec<u8>,
+    deltas: Vec<i8>
+}
+
+impl Secret {
+    pub fn new(number: usize) -> Self {
+        let mut s = Self {
+            number,
+            prices: Vec::new(),
+            deltas: Vec::new()
+        };
+        s.prices.push(s.price());
+        s
+    }
+
+    /// Evolve into the next secret number
+    pub fn next_number(&mut self) {
+        self.number = Self::prune(Self::mix(self.number * 64, self.number));
+        self.number = Self::prune(Self::mix(self.number / 32, self.number));
+        self.number = Self::prune(Self::mix(self.number * 2048, self.number));
+        self.prices.push(self.price());
+        self.deltas.push(self.prices[self.prices.len() - 1] as i8 - self.prices[self.prices.len() - 2] as i8); 
+    }
+
+    fn price(&self) -> u8 {
+        (self.number % 10) as u8
+    }
+
+    fn mix(a: usize, b: usize) -> usize {
+        a.bitxor(b)
+    }
+
+    fn prune(a: usize) -> usize {
+        a % 16777216
+    }
+
+    /// The price when the changes first match `deltas`, if ever
+    pub fn price_after_deltas(&self, deltas: [i8; 4]) -> Option<u8> {
+        for i in 0..(self.deltas.len() - 3) {
+            if self.deltas[i..(i+4)] == deltas {
+                return Some(self.prices[i+4]);
+            }
+        }
+        None
+    }
+}
+
+/// Each buyer's initial secret number, one per line
+pub fn parse(input: &str) -> Result<Vec<Secret>, ParseError> {
+    input.lines().enumerate().map(|(i, s)| {
+        parse_number(s, s).map(Secret::new).map_err(|e| e.with_line(i + 1))
+    }).collect()
+}
+
+/// Every line which isn't a secret number
+pub fn check(input: &str) -> Vec<ParseError> {
+    check_lines(input, |s| parse_number::<usize>(s, s))
+}
+
+/// Each buyer's secret number, after generating 2000 more
+pub fn simulate(input: &str) -> Result<Vec<Secret>, ParseError> {
+    let mut secrets = parse(input)?;
+    for _i in 0..2000 {
+        for s in &mut secrets {
+            s.next_number();
+        }
+    }
+    Ok(secrets)
+}
+
+/// The sum of each buyer's 2000th secret number
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(simulate(input)?.iter().map(|s| s.number).sum())
+}
+
+/// The most bananas which can be bought with one sequence of price changes
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(best_bananas(input)?.0)
+}
+
+/// The bananas bought by selling to each buyer when their prices first change by `deltas`
+pub fn bananas(secrets: &[Secret], deltas: [i8; 4]) -> usize {
+    let mut bananas: usize = 0;
+    for s in secrets {
+        if let Some(price) = s.price_after_deltas(deltas) {
+            bananas += price as usize;
+        }
+    }
+    bananas
+}
+
+/// The most bananas which can be bought, and the sequence of price changes which buys them
+pub fn best_bananas(input: &str) -> Result<(usize, Option<[i8; 4]>), ParseError> {
+    let secrets = simulate(input)?;
+    let min = -9;
+    let max = 10;
+    let mut best_bananas: usize = 0;
+    let mut best = None;
+    let mut progress = Progress::new("Price change sequences", ((max - min) as usize).pow(4));
+    for a in min..max {
+        for b in min..max {
+            for c in min..max {
+                for d in min..max {
+                    let bananas = bananas(&secrets, [a,b,c,d]);
+                    if bananas > best_bananas {
+                        best_bananas = bananas;
+                        best = Some([a,b,c,d]);
+                    }
+                    progress.step();
+                }
+            } 
+        }
+    }
+    Ok((best_bananas, best))
+}
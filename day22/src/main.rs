@@ -1,99 +1,18 @@
-use std::fs;
 use std::env;
-use std::ops::BitXor;
-
-#[derive(Debug)]
-struct Secret {
-    number: usize,
-    prices: Vec<u8>,
-    deltas: Vec<i8>
-}
-
-impl Secret {
-    fn new(number: usize) -> Self {
-        let mut s = Self {
-            number,
-            prices: Vec::new(),
-            deltas: Vec::new()
-        };
-        s.prices.push(s.price());
-        s
-    }
-
-    fn next_number(&mut self) {
-        self.number = Self::prune(Self::mix(self.number * 64, self.number));
-        self.number = Self::prune(Self::mix(self.number / 32, self.number));
-        self.number = Self::prune(Self::mix(self.number * 2048, self.number));
-        self.prices.push(self.price());
-        self.deltas.push(self.prices[self.prices.len() - 1] as i8 - self.prices[self.prices.len() - 2] as i8); 
-    }
-
-    fn price(&self) -> u8 {
-        (self.number % 10) as u8
-    }
-
-    fn mix(a: usize, b: usize) -> usize {
-        a.bitxor(b)
-    }
-
-    fn prune(a: usize) -> usize {
-        a % 16777216
-    }
-
-    fn price_after_deltas(&self, deltas: [i8; 4]) -> Option<u8> {
-        for i in 0..(self.deltas.len() - 3) {
-            if self.deltas[i..(i+4)] == deltas {
-                return Some(self.prices[i+4]);
-            }
-        }
-        None
-    }
-}
+use common::{check_flag, exit_check, log_flags, read_input_or_exit, STDIN};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let mut secrets: Vec<Secret> = text.lines().map(|s| Secret::new(s.parse::<usize>().unwrap())).collect();
-        for _i in 0..2000 {
-            for s in &mut secrets {
-                s.next_number();
-            }
-        }
-        let sum: usize = secrets.iter().map(|s| s.number).sum();
-        println!("Part1 sum: {:?}", sum);
-        let min = -9;
-        let max = 10;
-        let mut best_bananas: usize = 0;
-        let mut best = None;
-        let total = ((max as isize - min as isize) + 1).pow(4) as usize;
-        let mut progress = 0;
-        for a in min..max {
-            for b in min..max {
-                for c in min..max {
-                    for d in min..max {
-                        let mut bananas: usize = 0;
-                        for s in &secrets {
-                            if let Some(price) = s.price_after_deltas([a,b,c,d]) {
-                                bananas += price as usize;
-                            }
-                        }
-                        if bananas > best_bananas {
-                            best_bananas = bananas;
-                            best = Some([a,b,c,d]);
-                        }
-                        progress += 1;
-                        if progress % 1000 == 0 {
-                            println!("{}/{}={}%", progress, total, progress as f64 * 100 as f64 / total as f64);
-                        }
-                    }
-                } 
-            }
+    let (check, args) = check_flag(log_flags(env::args().collect()));
+    if args.len() <= 2 {
+        let filename = args.get(1).map_or(STDIN, |s| s.as_str());
+        let text = read_input_or_exit(filename);
+        if check {
+            exit_check(filename, day22::check(&text));
         }
+        println!("Part1 sum: {:?}", day22::part1(&text).unwrap_or_else(|e| e.exit(filename)));
+        let (best_bananas, best) = day22::best_bananas(&text).unwrap_or_else(|e| e.exit(filename));
         println!("Best bananas: {} ({:?})", best_bananas, best);
     } else {
-        println!("Please provide 1 argument: Filename");
+        println!("Please provide 1 argument: Filename (or - for stdin, the default), with --check to only validate it");
     }
 }
\ No newline at end of file
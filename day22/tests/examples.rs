@@ -0,0 +1,13 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day22::part1(input).unwrap(), 37327623);
+    assert_eq!(day22::part2(input).unwrap(), 24);
+}
+
+#[test]
+fn test2() {
+    let input = include_str!("../test2.txt");
+    assert_eq!(day22::part1(input).unwrap(), 37990510);
+    assert_eq!(day22::part2(input).unwrap(), 23);
+}
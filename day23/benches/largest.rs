@@ -0,0 +1,19 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use day23::FastNetwork;
+
+// from a fresh network each time, so the caches start empty (boxed, because passing the 520x520 array by value through criterion takes forever to compile)
+fn largest(c: &mut Criterion) {
+    let input = include_str!("../input.txt");
+    c.bench_function("day23 largest", |b| b.iter_batched(
+        || Box::new(day23::parse_fast::<520>(input).unwrap()),
+        |mut network: Box<FastNetwork<520>>| network.largest(),
+        BatchSize::LargeInput
+    ));
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = largest
+}
+criterion_main!(benches);
@@ -1,13 +1,12 @@
 use crate::{Computer, Selection};
-use std::time::Instant;
-use std::collections::HashMap;
+use common::{Cache, Progress, verbose};
 
 #[derive(Debug)]
 pub struct FastNetwork<const N: usize> {
-    pub pcs: [Computer; N],
-    pub map: [Selection<N>; N],
-    pub common_cache: HashMap<Selection<N>, Selection<N>>,
-    pub expand_cache: HashMap<Selection<N>, Selection<N>>
+    pub(crate) pcs: [Computer; N],
+    pub(crate) map: [Selection<N>; N],
+    pub(crate) common_cache: Cache<Selection<N>, Selection<N>>,
+    pub(crate) expand_cache: Cache<Selection<N>, Selection<N>>
 }
 
 impl<const N: usize> FastNetwork<N> {
@@ -22,20 +21,17 @@ impl<const N: usize> FastNetwork<N> {
 
     pub fn largest(&mut self) -> Selection<N> {
         let mut largest: Option<Selection<N>> = None;
-        let mut last = Instant::now();
-        let mut progress = 0;
+        let mut progress = Progress::new("Largest LAN party", N);
         for a in 0..N {
-            println!("Starting {:?} ({}/{}={}%)", a, progress, self.map.len(), progress as f64 * 100.0 / self.map.len() as f64);
+            progress.set(a);
             let lan = Selection::one(a);
             let result = self.expand_selection_to_largest(lan);
             if largest.is_none() || largest.as_ref().unwrap().count() < result.count() {
                 largest = Some(result);
             }
-            let duration = Instant::now() - last;
-            progress += 1;
-            println!("Took {}s", duration.as_secs());
-            last = Instant::now();
         }
+        verbose!("Expand cache: {}", self.expand_cache.stats());
+        verbose!("Common connections cache: {}", self.common_cache.stats());
         largest.unwrap()
     }
 
@@ -68,12 +64,12 @@ impl<const N: usize> FastNetwork<N> {
             cached.clone()
         } else {
             let indicies = pcs.selected();
-            if indicies.len() == 0 {
+            if indicies.is_empty() {
                 panic!("Tried to get common connections of nothing");
             }
             let mut common = self.map[indicies[0]].clone();
-            for i in 1..indicies.len() {
-                common.and(&self.map[indicies[i]]);
+            for i in indicies.iter().skip(1) {
+                common.and(&self.map[*i]);
             }
             self.common_cache.insert(pcs.clone(), common.clone());
             common
@@ -0,0 +1,220 @@
+//! Day 23: LAN Party
+
+use std::str::FromStr;
+use std::collections::{HashSet, HashMap};
+use common::{Cache, ParseError, check_lines, parse_lines};
+
+pub use crate::fast::FastNetwork;
+
+/// A computer's two letter name
+pub type Computer = [char; 2];
+
+/// A set of computers, by their index in the network
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Selection<const N: usize>([bool; N]);
+
+impl<const N: usize> Selection<N> {
+    fn one(index: usize) -> Self {
+        let mut bools = [false; N];
+        bools[index] = true;
+        Self(bools)
+    }
+
+    fn selected(&self) -> Vec<usize> {
+        let mut indicies = Vec::new();
+        for i in 0..N {
+            if self.0[i] {
+                indicies.push(i);
+            }
+        }
+        indicies
+    }
+
+    fn count(&self) -> usize {
+        let mut count = 0;
+        for i in 0..N {
+            if self.0[i] {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    fn and(&mut self, other: &Self) {
+        for i in 0..N {
+            self.0[i] &= other.0[i]
+        }
+    }
+}
+
+struct Pair(Computer, Computer);
+
+mod fast;
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let computers: Vec<&str> = line.split("-").collect();
+        if computers.len()!= 2 {
+            return Err(ParseError::new("2 computers separated by -", line));
+        }
+        Ok(Self(parse_computer(line, computers[0])?, parse_computer(line, computers[1])?))
+    }
+}
+
+fn parse_computer(line: &str, name: &str) -> Result<Computer, ParseError> {
+    name.chars().collect::<Vec<char>>().try_into().map_err(|_| ParseError::at(line, name, "a 2 letter computer name"))
+}
+
+/// Every computer, and which others each is connected to
+pub struct Network {
+    pcs: Vec<Computer>,
+    map: HashMap<Computer, HashSet<Computer>>
+}
+
+impl Network {
+    fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            pcs: Vec::new()
+        }
+    }
+
+    fn from(pairs: Vec<Pair>) -> Self {
+        let mut map = Self::new();
+        for p in pairs {
+            map.add(p.0, p.1);
+            map.add(p.1, p.0);
+        }
+        map
+    }
+
+    fn add(&mut self, k: Computer, v: Computer) {
+        if let Some(existing) = self.map.get_mut(&k) {
+            existing.insert(v);
+        } else {
+            let mut new = HashSet::new();
+            new.insert(v);
+            self.map.insert(k, new);
+            self.pcs.push(k);
+            self.pcs.sort();
+        }
+    }
+
+    /// Every set of 3 computers which are all connected to each other, where at least one name starts with `starts_with`
+    pub fn triples(&self, starts_with: char) -> HashSet<(Computer, Computer, Computer)> {
+        let mut set = HashSet::new();
+        for (a, a_set) in &self.map {
+            for b in a_set {
+                if let Some(b_set) = self.map.get(b) {
+                    for c in a_set.intersection(b_set) {
+                        let mut triple = [a, b, c];
+                        if triple.iter().any(|s| s[0] == starts_with) {
+                            triple.sort(); // in alphabetical order to de-duplicate
+                            set.insert((*triple[0], *triple[1], *triple[2]));
+                        }
+                    }
+                }
+            }
+        }
+        set
+    }
+
+    /// The largest set of computers which are all connected to each other, using Bron-Kerbosch on the sets directly
+    pub fn largest(&self) -> Vec<Computer> {
+        let mut largest = Vec::new();
+        self.expand(&mut Vec::new(), self.pcs.clone(), HashSet::new(), &mut largest);
+        largest.sort();
+        largest
+    }
+
+    fn expand(&self, lan: &mut Vec<Computer>, mut candidates: Vec<Computer>, mut excluded: HashSet<Computer>, largest: &mut Vec<Computer>) {
+        if candidates.is_empty() && excluded.is_empty() {
+            if lan.len() > largest.len() {
+                *largest = lan.clone();
+            }
+            return;
+        }
+        while let Some(pc) = candidates.pop() {
+            let connected = &self.map[&pc];
+            lan.push(pc);
+            let next_candidates = candidates.iter().filter(|c| connected.contains(*c)).cloned().collect();
+            self.expand(lan, next_candidates, excluded.intersection(connected).cloned().collect(), largest);
+            lan.pop();
+            excluded.insert(pc);
+        }
+    }
+
+    /// Convert to a FastNetwork, which must have exactly N computers
+    pub fn to_fast<const N: usize>(&self) -> FastNetwork<N> {
+        if self.pcs.len() != N {
+            panic!("Wrong length: {}", self.pcs.len());
+        }
+        let mut pcs = Vec::new();
+        let mut map = Vec::new();
+        for pc in self.pcs.iter() {
+            pcs.push(*pc);
+            let set = self.map.get(pc).unwrap();
+            let mut selected = Vec::new();
+            for mapped_pc in self.pcs.iter() {
+                selected.push(set.contains(mapped_pc));
+            }
+            map.push(Selection(selected.try_into().unwrap()));
+        }
+        FastNetwork {
+            pcs: pcs.try_into().unwrap(),
+            map: map.try_into().unwrap(),
+            common_cache: Cache::new(),
+            expand_cache: Cache::new()
+        }
+    }
+}
+
+/// One connection between 2 computers per line
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    let connections: Vec<Pair> = parse_lines(input)?;
+    Ok(Network::from(connections))
+}
+
+/// Every line which isn't a connection between 2 computers
+pub fn check(input: &str) -> Vec<ParseError> {
+    check_lines(input, Pair::from_str)
+}
+
+/// Parse a network of exactly N computers, ready to search for the largest LAN party
+pub fn parse_fast<const N: usize>(input: &str) -> Result<FastNetwork<N>, ParseError> {
+    let network = parse(input)?;
+    if network.pcs.len() != N {
+        return Err(ParseError::new(format!("{} computers", N), format!("{} computers", network.pcs.len())));
+    }
+    Ok(network.to_fast())
+}
+
+/// The number of sets of 3 inter-connected computers where at least one name starts with t
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(parse(input)?.triples('t').len())
+}
+
+/// The same as part 2, but searching the network without converting it to a FastNetwork (so it works for any number of computers)
+pub fn largest_lan_party(input: &str) -> Result<String, ParseError> {
+    let network = parse(input)?;
+    Ok(network.largest().iter().flat_map(|pc| pc.iter()).collect())
+}
+
+/// The password to the LAN party (the names of the largest set of inter-connected computers, sorted and joined),
+/// if the network is the size of the example or the input
+pub fn part2(input: &str) -> Result<Option<String>, ParseError> {
+    let network = parse(input)?;
+    if network.pcs.len() == 16 {
+        let mut fast: FastNetwork<16> = network.to_fast();
+        let largest = fast.largest();
+        Ok(Some(fast.display(&largest)))
+    } else if network.pcs.len() == 520 {
+        let mut fast: FastNetwork<520> = network.to_fast();
+        let largest = fast.largest();
+        Ok(Some(fast.display(&largest)))
+    } else {
+        Ok(None)
+    }
+}
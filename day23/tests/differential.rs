@@ -0,0 +1,27 @@
+use std::collections::HashSet;
+
+// the size of a LAN party (its password), after checking that every computer in it is connected to every other
+fn party_size(input: &str, password: &str) -> usize {
+    let links: HashSet<(&str, &str)> = input.lines().filter_map(|line| line.split_once('-')).flat_map(|(a, b)| [(a, b), (b, a)]).collect();
+    let party: Vec<&str> = password.split(',').collect();
+    for (i, a) in party.iter().enumerate() {
+        for b in &party[(i + 1)..] {
+            assert!(links.contains(&(*a, *b)), "{} and {} in {} aren't connected", a, b, password);
+        }
+    }
+    party.len()
+}
+
+// the bitset search of FastNetwork should find a LAN party as big as searching the HashMap of sets directly,
+// though not necessarily the same one if there is more than one of the largest size
+#[test]
+fn fast_network_matches_network() {
+    generate::assert_agree(23, 16, 20,
+        |input| party_size(input, &day23::part2(input).unwrap().unwrap()),
+        |input| party_size(input, &day23::largest_lan_party(input).unwrap()));
+}
+
+#[test]
+fn network_solves_example() {
+    assert_eq!(day23::largest_lan_party(include_str!("../test.txt")).unwrap(), "codekata");
+}
@@ -0,0 +1,6 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day23::part1(input).unwrap(), 7);
+    assert_eq!(day23::part2(input).unwrap(), Some("codekata".to_string()));
+}
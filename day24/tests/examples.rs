@@ -0,0 +1,44 @@
+// part2 isn't solved yet
+
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day24::part1(input).unwrap(), 4);
+}
+
+#[test]
+fn test2() {
+    let input = include_str!("../test2.txt");
+    assert_eq!(day24::part1(input).unwrap(), 2024);
+}
+
+// x + y for 2 bits, without the carry out of the top bit
+const ADDER: &str = "x00: 1\nx01: 0\ny00: 0\ny01: 1\n\nx00 XOR y00 -> z00\nx00 AND y00 -> c00\nx01 XOR y01 -> s01\ns01 XOR c00 -> z01\n";
+
+#[test]
+fn test_investigate() {
+    assert_eq!(day24::part1(ADDER).unwrap(), 0b11);
+    assert_eq!(day24::investigate(ADDER).unwrap(), None);
+    let swapped = ADDER.replace("-> z00", "-> zz").replace("-> z01", "-> z00").replace("-> zz", "-> z01");
+    let invalid = day24::investigate(&swapped).unwrap().unwrap();
+    assert_eq!(invalid.output, "z00");
+    assert!(invalid.swaps.contains(&("z00".to_string(), "z01".to_string())), "{:?}", invalid.swaps);
+    // a wire which looks like an input bit but isn't numbered is an error rather than a panic
+    let error = day24::investigate("x00: 1\nxab: 0\n\nx00 XOR xab -> z00\n").unwrap_err();
+    assert_eq!((error.expected.as_str(), error.found.as_str()), ("x or y followed by a bit number", "xab"));
+}
+
+#[test]
+fn test_invalid_wires() {
+    // a gate input which is never set
+    let input = "x00: 1\n\nx00 AND y00 -> z00\n";
+    let error = day24::parse(input).unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (Some(3), Some(9), "y00"));
+    assert_eq!(day24::check(input), vec![error]);
+    // gates which can only be calculated from each other
+    let input = "x00: 1\n\nx00 AND a -> b\nx00 OR b -> a\nb XOR x00 -> z00\n";
+    let error = day24::part1(input).unwrap_err();
+    assert_eq!(error.found, "a loop through a");
+    assert_eq!(day24::check(input), vec![error]);
+    assert!(day24::investigate(input).is_err());
+}
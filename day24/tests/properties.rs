@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+const OPERATIONS: [&str; 3] = ["AND", "OR", "XOR"];
+
+// A random circuit, where each gate's inputs are initial wires or the outputs of earlier gates
+#[derive(Debug, Clone)]
+struct Circuit {
+    values: Vec<(String, bool)>,
+    gates: Vec<Gate>
+}
+
+#[derive(Debug, Clone)]
+struct Gate {
+    input_a: String,
+    operation: &'static str,
+    input_b: String,
+    output: String
+}
+
+impl Circuit {
+    fn input(&self, gate_order: &[usize]) -> String {
+        let mut text = String::new();
+        for (wire, value) in &self.values {
+            text.push_str(&format!("{}: {}\n", wire, if *value { 1 } else { 0 }));
+        }
+        text.push('\n');
+        for i in gate_order {
+            let gate = &self.gates[*i];
+            text.push_str(&format!("{} {} {} -> {}\n", gate.input_a, gate.operation, gate.input_b, gate.output));
+        }
+        text
+    }
+
+    // every gate comes after its inputs, so they can just be evaluated in order
+    fn evaluate(&self) -> HashMap<String, bool> {
+        let mut values: HashMap<String, bool> = self.values.iter().cloned().collect();
+        for gate in &self.gates {
+            let a = values[&gate.input_a];
+            let b = values[&gate.input_b];
+            let value = match gate.operation {
+                "AND" => a && b,
+                "OR" => a || b,
+                _ => a ^ b
+            };
+            values.insert(gate.output.clone(), value);
+        }
+        values
+    }
+}
+
+fn circuit() -> impl Strategy<Value = Circuit> {
+    let bits = 1..=5usize;
+    let gates = prop::collection::vec((0..3usize, any::<Index>(), any::<Index>()), 1..40);
+    (bits, gates, any::<u64>(), 1..=4usize).prop_map(|(bits, gate_choices, initial, outputs)| {
+        let mut values = Vec::new();
+        for i in 0..bits {
+            values.push((format!("x{:02}", i), initial & (1 << i) != 0));
+            values.push((format!("y{:02}", i), initial & (1 << (i + 32)) != 0));
+        }
+        let mut wires: Vec<String> = values.iter().map(|(w, _)| w.clone()).collect();
+        let mut gates = Vec::new();
+        let first_output = gate_choices.len().saturating_sub(outputs);
+        for (i, (operation, a, b)) in gate_choices.into_iter().enumerate() {
+            let output = if i >= first_output {
+                format!("z{:02}", i - first_output)
+            } else {
+                format!("g{:02}", i)
+            };
+            gates.push(Gate {
+                input_a: a.get(&wires).clone(),
+                operation: OPERATIONS[operation],
+                input_b: b.get(&wires).clone(),
+                output: output.clone()
+            });
+            wires.push(output);
+        }
+        Circuit {
+            values,
+            gates
+        }
+    })
+}
+
+// the gates in any order, since calculate has to work out which are ready
+fn circuit_and_order() -> impl Strategy<Value = (Circuit, Vec<usize>)> {
+    circuit().prop_flat_map(|circuit| {
+        let order = Just((0..circuit.gates.len()).collect::<Vec<_>>()).prop_shuffle();
+        (Just(circuit), order)
+    })
+}
+
+proptest! {
+    #[test]
+    fn calculate_matches_evaluating_in_order((circuit, order) in circuit_and_order()) {
+        let mut logic = day24::parse(&circuit.input(&order)).unwrap();
+        logic.calculate().unwrap();
+        for (wire, value) in circuit.evaluate() {
+            prop_assert_eq!(logic.value(&wire), Some(value), "wire {}", wire);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Day 25: Code Chronicle
+
+use std::str::FromStr;
+use common::{ParseError, sections};
+
+/// The number of pins in every lock (and so the number of columns in every schematic)
+pub const PINS: usize = 5;
+/// How far a pin can move, which is the number of rows between the top and bottom row of a schematic
+pub const SPACE: usize = 5;
+
+/// The height of each pin, from the top for a lock or from the bottom for a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pins {
+    pub heights: [usize; PINS]
+}
+
+/// A lock (filled in from the top) or a key (filled in from the bottom)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Schematic {
+    Lock(Pins),
+    Key(Pins)
+}
+
+impl Pins {
+    /// A lock and key fit if no column overlaps, even though they might not be a match
+    pub fn fits(&self, other: &Pins) -> bool {
+        self.heights.iter().zip(other.heights.iter()).all(|(a, b)| a + b <= SPACE)
+    }
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(section: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = section.lines().collect();
+        if lines.len() != SPACE + 2 {
+            return Err(ParseError::new(format!("{} lines of # and .", SPACE + 2), format!("{} lines", lines.len())));
+        }
+        for (i, line) in lines.iter().enumerate() {
+            if line.len() != PINS || !line.is_ascii() {
+                return Err(ParseError::new(format!("{} columns of # and .", PINS), *line).with_line(i + 1));
+            }
+        }
+        // a lock is filled from the top row down, a key from the bottom row up
+        let (lock, rows): (bool, Vec<(usize, &str)>) = if lines[0] == "#".repeat(PINS) {
+            (true, lines.iter().copied().enumerate().collect())
+        } else if lines[SPACE + 1] == "#".repeat(PINS) {
+            (false, lines.iter().copied().enumerate().rev().collect())
+        } else {
+            return Err(ParseError::new("a lock (top row filled) or a key (bottom row filled)", lines[0]).with_line(1));
+        };
+        let mut heights = [0; PINS];
+        for (col, height) in heights.iter_mut().enumerate() {
+            let mut filled = true;
+            for (count, (i, line)) in rows.iter().enumerate() {
+                let cell = &line[col..(col + 1)];
+                match (cell, filled) {
+                    ("#", true) if count <= SPACE => *height = count,
+                    (".", _) => filled = false,
+                    _ => {
+                        let expected = if filled && count <= SPACE { "# or ." } else { ". (past the end of the pin)" };
+                        return Err(ParseError::at(line, cell, expected).with_line(i + 1));
+                    }
+                }
+            }
+        }
+        let pins = Pins {
+            heights
+        };
+        Ok(if lock { Self::Lock(pins) } else { Self::Key(pins) })
+    }
+}
+
+/// The locks and keys, from one schematic per section
+pub fn parse(input: &str) -> Result<(Vec<Pins>, Vec<Pins>), ParseError> {
+    let mut locks = Vec::new();
+    let mut keys = Vec::new();
+    for section in sections(input) {
+        match section.parse()? {
+            Schematic::Lock(pins) => locks.push(pins),
+            Schematic::Key(pins) => keys.push(pins)
+        }
+    }
+    Ok((locks, keys))
+}
+
+/// Every schematic which isn't a lock or key
+pub fn check(input: &str) -> Vec<ParseError> {
+    sections(input).iter().filter_map(|s| s.parse::<Schematic>().err()).collect()
+}
+
+/// How many pairs of lock and key fit together
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let (locks, keys) = parse(input)?;
+    Ok(locks.iter().map(|lock| keys.iter().filter(|key| lock.fits(key)).count()).sum())
+}
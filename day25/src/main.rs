@@ -0,0 +1,16 @@
+use std::env;
+use common::{check_flag, exit_check, log_flags, read_input_or_exit, STDIN};
+
+fn main() {
+    let (check, args) = check_flag(log_flags(env::args().collect()));
+    if args.len() <= 2 {
+        let filename = args.get(1).map_or(STDIN, |s| s.as_str());
+        let text = read_input_or_exit(filename);
+        if check {
+            exit_check(filename, day25::check(&text));
+        }
+        println!("Fitting pairs: {}", day25::part1(&text).unwrap_or_else(|e| e.exit(filename)));
+    } else {
+        println!("Please provide 1 argument: Filename (or - for stdin, the default), with --check to only validate it");
+    }
+}
\ No newline at end of file
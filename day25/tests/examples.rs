@@ -0,0 +1,15 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day25::part1(input).unwrap(), 3);
+}
+
+#[test]
+fn test_heights() {
+    let input = include_str!("../test.txt");
+    let (locks, keys) = day25::parse(input).unwrap();
+    assert_eq!(locks.iter().map(|l| l.heights).collect::<Vec<_>>(), vec![[0, 5, 3, 4, 3], [1, 2, 0, 5, 3]]);
+    assert_eq!(keys.iter().map(|k| k.heights).collect::<Vec<_>>(), vec![[5, 0, 2, 1, 3], [4, 3, 4, 0, 2], [3, 0, 2, 0, 1]]);
+    assert!(!locks[0].fits(&keys[0]));
+    assert!(locks[0].fits(&keys[2]));
+}
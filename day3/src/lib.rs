@@ -0,0 +1,40 @@
+//! Day 3: Mull It Over
+
+use regex::Regex;
+use common::ParseError;
+
+/// The sum of all the mul(a,b) instructions, and the sum of only those enabled by do() rather than don't()
+pub fn sums(text: &str) -> (usize, usize) {
+    let re = Regex::new(r"mul\((\d\d?\d?),(\d\d?\d?)\)|(don't)(\(\))|(do)(\(\))").unwrap();
+    let mut sum = 0;
+    let mut enabled = true;
+    let mut sum_with_enabled = 0;
+    for (_, [a, b]) in re.captures_iter(text).map(|c| c.extract()) {
+        if b == "()" {
+            enabled = a == "do";
+        } else {
+            let n1: usize = a.parse().unwrap();
+            let n2: usize = b.parse().unwrap();
+            sum += n1 * n2;
+            if enabled {
+                sum_with_enabled += n1 * n2;
+            }
+        }
+    }
+    (sum, sum_with_enabled)
+}
+
+/// Nothing, since the instructions are found amongst corrupted memory and so any text is valid
+pub fn check(_input: &str) -> Vec<ParseError> {
+    Vec::new()
+}
+
+/// The sum of all the multiplications
+pub fn part1(input: &str) -> usize {
+    sums(input).0
+}
+
+/// The sum of the enabled multiplications
+pub fn part2(input: &str) -> usize {
+    sums(input).1
+}
@@ -0,0 +1,22 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day3::part1(input), 161);
+    assert_eq!(day3::part2(input), 161);
+}
+
+#[test]
+fn test2() {
+    let input = include_str!("../test2.txt");
+    assert_eq!(day3::part1(input), 161);
+    assert_eq!(day3::part2(input), 48);
+}
+
+#[test]
+fn test_missing_file() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_day3")).arg("missing.txt").output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let error = String::from_utf8_lossy(&output.stderr);
+    assert!(error.starts_with("Error reading from missing.txt: "), "{}", error);
+    assert!(!error.contains("panicked"));
+}
@@ -0,0 +1,182 @@
+//! Day 4: Ceres Search
+
+use std::str::FromStr;
+use grid::{Grid, Image, Rgb, SignedPos};
+use common::ParseError;
+
+/// A grid of letters, read in every direction
+#[derive(Debug)]
+pub struct WordSearch {
+    lines: Vec<Vec<char>>,
+    cols: Vec<Vec<char>>,
+    diagonals: Vec<Vec<char>>
+}
+
+impl FromStr for WordSearch {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(text, letter)?;
+        let lines = grid.to_rows();
+        let cols = grid.transpose().to_rows();
+        let max_x = cols.len() - 1;
+        let max_y = lines.len() - 1;
+        let mut diagonals = Vec::new();
+        for i in 0..(max_x + 1) {
+            let mut x = i;
+            let mut y = 0;
+            let mut d = Vec::new();
+            while x <= max_x && y <= max_y {
+                d.push(lines[y][x]);
+                x += 1;
+                y += 1;
+            }
+            diagonals.push(d);
+            
+        }
+        for i in 1..(max_y + 1) {
+            let mut x = 0;
+            let mut y = i;
+            let mut d = Vec::new();
+            while x <= max_x && y <= max_y {
+                d.push(lines[y][x]);
+                x += 1;
+                y += 1;
+            }
+            diagonals.push(d);
+        }
+        for i in 0..(max_x + 1) {
+            let mut x = i;
+            let mut y = 0;
+            let mut d = Vec::new();
+            while y <= max_y {
+                d.push(lines[y][x]);
+                if x == 0 {
+                    break;
+                }
+                x -= 1;
+                y += 1;
+            }
+            diagonals.push(d);
+            
+        }
+        for i in 1..(max_y + 1) {
+            let mut x = max_x;
+            let mut y = i;
+            let mut d = Vec::new();
+            while y <= max_y {
+                d.push(lines[y][x]);
+                if x == 0 {
+                    break;
+                }
+                x -= 1;
+                y += 1;
+            }
+            diagonals.push(d);
+        }
+        Ok(Self{ lines, cols, diagonals })
+    }
+}
+
+impl WordSearch {
+    /// How many times a 4 letter word appears, horizontally, vertically or diagonally (forwards or backwards)
+    pub fn find(&self, s: &[char; 4]) -> usize {
+        let mut total = 0;
+        for l in &self.lines {
+            total += Self::find_in_vec(s, l);
+            total += Self::find_in_vec(s, &l.iter().rev().cloned().collect::<Vec<char>>());
+        }
+        for c in &self.cols {
+            total += Self::find_in_vec(s, c);
+            total += Self::find_in_vec(s, &c.iter().rev().cloned().collect::<Vec<char>>());
+        }
+        for d in &self.diagonals {
+            total += Self::find_in_vec(s, d);
+            total += Self::find_in_vec(s, &d.iter().rev().cloned().collect::<Vec<char>>());
+        }
+        total
+    }
+
+    fn find_in_vec(s: &[char; 4], v: &[char]) -> usize {
+        if v.len() < 4 {
+            return 0;
+        }
+        let mut count = 0;
+        for i in 0..(v.len() - 4 + 1) {
+            if &v[i..(i+4)] == s {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// How many times two MAS cross in the shape of an X
+    pub fn find_x(&self) -> usize {
+        Self::find_x_vec(&self.lines)
+    }
+
+    fn find_x_vec(v: &[Vec<char>]) -> usize {
+        let mut count = 0;
+        for y in 0..(v.len() - 2) {
+            for x in 0..(v[0].len() -2) {
+                if (v[y][x] == 'M' && v[y + 1][x + 1] == 'A' && v[y + 2][x] == 'M' && v[y][x + 2] == 'S' && v[y + 2][x + 2] == 'S')
+                    || (v[y][x] == 'M' && v[y + 1][x + 1] == 'A' && v[y + 2][x] == 'S' && v[y][x + 2] == 'M' && v[y + 2][x + 2] == 'S')
+                    || (v[y][x] == 'S' && v[y + 1][x + 1] == 'A' && v[y + 2][x] == 'M' && v[y][x + 2] == 'S' && v[y + 2][x + 2] == 'M')
+                    || (v[y][x] == 'S' && v[y + 1][x + 1] == 'A' && v[y + 2][x] == 'S' && v[y][x + 2] == 'M' && v[y + 2][x + 2] == 'M')
+                {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+}
+
+fn letter(ch: char) -> Result<char, ParseError> {
+    if ch.is_ascii_uppercase() || ch == '.' {
+        Ok(ch)
+    } else {
+        Err(ParseError::new("a letter (A-Z) or .", ch.to_string()))
+    }
+}
+
+/// The letters, with every letter of an XMAS in yellow (and any others in grey)
+pub fn image(input: &str, scale: usize) -> Result<Image, ParseError> {
+    let letters = Grid::parse(input, letter)?;
+    let mut image = Image::with_palette(&letters, scale, |ch| if *ch == '.' { Rgb::BLACK } else { Rgb::GREY });
+    for start in letters.positions() {
+        for step in SignedPos::new(0, 0).surrounding() {
+            let word: Vec<SignedPos> = (0..4).map(|i| SignedPos::from(start).delta(step.row * i, step.col * i)).collect();
+            if word.iter().zip("XMAS".chars()).all(|(pos, ch)| letters.get_signed(pos) == Some(&ch)) {
+                for pos in word {
+                    if let Ok(pos) = pos.try_into() {
+                        image.paint(&pos, Rgb::YELLOW);
+                    }
+                }
+            }
+        }
+    }
+    Ok(image)
+}
+
+pub fn parse(input: &str) -> Result<WordSearch, ParseError> {
+    input.parse()
+}
+
+/// Every line which isn't a row of letters (or .) as wide as the first
+pub fn check(input: &str) -> Vec<ParseError> {
+    Grid::check(input, letter)
+}
+
+/// The number of times XMAS appears
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let grid = parse(input)?;
+    let search: [char; 4] = ['X','M','A','S'];
+    Ok(grid.find(&search))
+}
+
+/// The number of X-MAS crosses
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let grid = parse(input)?;
+    Ok(grid.find_x())
+}
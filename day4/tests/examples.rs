@@ -0,0 +1,27 @@
+#[test]
+fn basic() {
+    let input = include_str!("../basic.txt");
+    assert_eq!(day4::part1(input).unwrap(), 4);
+    assert_eq!(day4::part2(input).unwrap(), 0);
+}
+
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day4::part1(input).unwrap(), 18);
+    assert_eq!(day4::part2(input).unwrap(), 9);
+}
+
+#[test]
+fn test2() {
+    let input = include_str!("../test2.txt");
+    assert_eq!(day4::part1(input).unwrap(), 0);
+    assert_eq!(day4::part2(input).unwrap(), 9);
+}
+
+#[test]
+fn test_image() {
+    let input = include_str!("../test.txt");
+    let image = day4::image(input, 3).unwrap();
+    assert_eq!((image.width(), image.height()), (30, 30));
+}
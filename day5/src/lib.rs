@@ -0,0 +1,135 @@
+//! Day 5: Print Queue
+
+use std::str::FromStr;
+use std::collections::HashSet;
+use common::{ParseError, parse_number, split_sections};
+
+/// The page ordering rules, and the updates to check against them
+pub struct Set {
+    pub orders: Vec<PageOrder>,
+    pub updates: Vec<Update>
+}
+
+/// A rule that if both pages are in an update, the first must come before the second
+#[derive(Debug, Copy, Clone)]
+pub struct PageOrder {
+    pub first: usize,
+    pub second: usize
+}
+
+/// The pages to print, in order
+#[derive(Debug, Clone)]
+pub struct Update(pub Vec<usize>);
+
+impl FromStr for Set {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let sections = split_sections(text, 2, "page orders and updates separated by a blank line")?;
+        let orders = sections[0].parse_lines()?;
+        let updates = sections[1].parse_lines()?;
+        Ok(Self {
+            orders,
+            updates
+        })
+    }
+}
+
+impl FromStr for PageOrder {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let pages: Vec<usize> = line.split("|").map(|p| parse_number(line, p)).collect::<Result<_, _>>()?;
+        if pages.len() != 2 {
+            return Err(ParseError::new("2 pages separated by |", line));
+        }
+        Ok(Self {
+            first: pages[0],
+            second: pages[1]
+        })
+    }
+}
+
+impl FromStr for Update {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let pages: Vec<usize> = line.split(",").map(|p| parse_number(line, p)).collect::<Result<_, _>>()?;
+        Ok(Self(pages))
+    }
+}
+
+impl Update {
+    /// Whether the pages are in an order which follows every rule
+    pub fn all_valid(&self, orders: &[PageOrder]) -> bool {
+        orders.iter().all(|o| self.one_valid(o))
+    }
+
+    fn one_valid(&self, order: &PageOrder) -> bool {
+        let p1 = self.0.iter().position(|&p| p == order.first);
+        let p2 = self.0.iter().position(|&p| p == order.second);
+        if let (Some(p1), Some(p2)) = (p1, p2) {
+            p1 < p2
+        } else {
+            true
+        }
+    }
+
+    pub fn middle_number(&self) -> usize {
+        let middle: usize = self.0.len() / 2;
+        self.0[middle]
+    }
+
+    /// The same pages, reordered to follow the rules
+    pub fn fix(&self, orders: &Vec<PageOrder>) -> Self {
+        let mut remaining = HashSet::new();
+        for n in &self.0 {
+            remaining.insert(n);
+        }
+        let mut orders_that_matter: Vec<PageOrder> = Vec::new();
+        for o in orders {
+            if remaining.contains(&o.first) && remaining.contains(&o.second) { // otherwise it doesn't matter
+                orders_that_matter.push(*o);
+            }
+        }
+        let mut new_update = Vec::new();
+        for next in &self.0 {
+            let must_be_before_values: Vec<usize> = orders_that_matter.iter().filter(|o| o.first == *next).map(|o| o.second).collect();
+            let must_be_before_indices = must_be_before_values.iter().filter_map(|v| new_update.iter().position(|p| p == v));
+            if let Some(can_go_at_index) = must_be_before_indices.min() {
+                new_update.insert(can_go_at_index, *next);
+            } else {
+                new_update.push(*next);
+            }
+            
+        }
+        Self(new_update)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Set, ParseError> {
+    input.parse()
+}
+
+/// Every line which isn't a page order (in the first section) or an update (in the second)
+pub fn check(input: &str) -> Vec<ParseError> {
+    let sections = match split_sections(input, 2, "page orders and updates separated by a blank line") {
+        Ok(sections) => sections,
+        Err(e) => return vec![e]
+    };
+    let mut errors = sections[0].check_lines(PageOrder::from_str);
+    errors.extend(sections[1].check_lines(Update::from_str));
+    errors
+}
+
+/// The sum of the middle pages of the updates which are already in order
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let set = parse(input)?;
+    Ok(set.updates.iter().filter(|u| u.all_valid(&set.orders)).map(|u| u.middle_number()).sum())
+}
+
+/// The sum of the middle pages of the updates which were out of order, once they are fixed
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let set = parse(input)?;
+    Ok(set.updates.iter().filter(|u| !u.all_valid(&set.orders)).map(|u| u.fix(&set.orders).middle_number()).sum())
+}
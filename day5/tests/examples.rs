@@ -0,0 +1,6 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day5::part1(input).unwrap(), 143);
+    assert_eq!(day5::part2(input).unwrap(), 123);
+}
@@ -0,0 +1,41 @@
+use proptest::prelude::*;
+use day5::{PageOrder, Update};
+
+// as in the puzzle, there is a rule for every pair of pages, all agreeing with one order of the pages
+fn orders_and_update() -> impl Strategy<Value = (Vec<PageOrder>, Update)> {
+    prop::sample::subsequence((10..100usize).collect::<Vec<_>>(), 2..25)
+        .prop_shuffle()
+        .prop_flat_map(|order| {
+            let mut orders = Vec::new();
+            for i in 0..order.len() {
+                for j in (i + 1)..order.len() {
+                    orders.push(PageOrder {
+                        first: order[i],
+                        second: order[j]
+                    });
+                }
+            }
+            let len = order.len();
+            (Just(orders), prop::sample::subsequence(order, 1..=len).prop_shuffle())
+        })
+        .prop_map(|(orders, pages)| (orders, Update(pages)))
+}
+
+proptest! {
+    #[test]
+    fn fix_is_a_valid_permutation((orders, update) in orders_and_update()) {
+        let fixed = update.fix(&orders);
+        prop_assert!(fixed.all_valid(&orders));
+        let mut before = update.0.clone();
+        let mut after = fixed.0.clone();
+        before.sort();
+        after.sort();
+        prop_assert_eq!(before, after);
+    }
+
+    #[test]
+    fn fix_keeps_fixed_updates((orders, update) in orders_and_update()) {
+        let fixed = update.fix(&orders);
+        prop_assert_eq!(fixed.fix(&orders).0, fixed.0);
+    }
+}
@@ -0,0 +1,19 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day6::{Map, obstacle_candidates};
+
+// every obstacle tried by part 2, sorted so each run checks them in the same order
+fn ends_in_loop(c: &mut Criterion) {
+    let map: Map = include_str!("../input.txt").parse().unwrap();
+    let mut candidates: Vec<_> = obstacle_candidates(&map).into_iter().collect();
+    candidates.sort();
+    c.bench_function("day6 ends_in_loop", |b| b.iter(|| {
+        candidates.iter().filter(|p| map.with_obstacle(**p).ends_in_loop()).count()
+    }));
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = ends_in_loop
+}
+criterion_main!(benches);
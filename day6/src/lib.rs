@@ -0,0 +1,173 @@
+//! Day 6: Guard Gallivant
+
+use std::str::FromStr;
+use std::collections::HashSet;
+use grid::{Direction, Grid, Image, Pos, Rgb, Simulation};
+use common::ParseError;
+
+/// The lab, with its obstacles and where the guard is and which way they are facing
+#[derive(Clone)]
+pub struct Map {
+    obstacles: Grid<bool>,
+    guard: Pos,
+    facing: Direction
+}
+
+#[derive(Hash, Eq, PartialEq, Clone)]
+struct State(Pos, Direction);
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let chars = Grid::parse(text, tile)?;
+        let obstacles = chars.map(|c| *c == '#');
+        let guard = chars.position(|c| *c == '^').ok_or(ParseError::new("a guard (^)", ""))?;
+        Ok(Self {
+            obstacles,
+            guard,
+            facing: Direction::Up
+        })
+    }
+}
+
+impl Map {
+    /// Turn right until the way ahead is clear, then step forward, returning false if the guard left the area instead
+    pub fn move_guard(&mut self) -> bool {
+        if let Some(in_front) = self.obstacles.step(&self.guard, self.facing) {
+            if self.obstacles[in_front] {
+                // obstactle in front
+                self.facing = self.facing.rotate_right();
+                self.move_guard()
+            } else {
+                // empty in front
+                self.guard = in_front;
+                true
+            }
+        } else {
+            // left the area
+            false
+        }
+    }
+
+    /// A copy of this map with an extra obstacle at `pos`
+    pub fn with_obstacle(&self, pos: Pos) -> Self {
+        let mut map = self.clone();
+        map.obstacles[pos] = true;
+        map
+    }
+
+    /// Move the guard until they either leave the area or return to a position they were already in, facing the same way
+    pub fn ends_in_loop(&mut self) -> bool {
+        let mut states = HashSet::new();
+        states.insert(State(self.guard, self.facing));
+        while self.move_guard() {
+            if !states.insert(State(self.guard, self.facing)) {
+                // we were already in this state, therefore loop
+                return true;
+            }
+        }
+        false
+    }
+}
+
+fn visited(map: &Map) -> HashSet<Pos> {
+    let mut map = map.clone();
+    let mut positions = HashSet::new();
+    positions.insert(map.guard);
+    while map.move_guard() {
+        positions.insert(map.guard);
+    }
+    positions
+}
+
+/// Every position where a new obstacle could change the guard's route (anywhere it visits, except where it starts)
+pub fn obstacle_candidates(map: &Map) -> HashSet<Pos> {
+    let mut positions = visited(map);
+    positions.remove(&map.guard);
+    positions
+}
+
+/// The guard walking their route, marking each position they visit
+pub struct Patrol {
+    map: Map,
+    visited: HashSet<Pos>
+}
+
+impl Patrol {
+    pub fn new(map: Map) -> Self {
+        let mut visited = HashSet::new();
+        visited.insert(map.guard);
+        Self {
+            map,
+            visited
+        }
+    }
+}
+
+impl Simulation for Patrol {
+    fn step(&mut self) -> bool {
+        if self.map.move_guard() {
+            self.visited.insert(self.map.guard);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn frame(&self) -> String {
+        let mut chars = self.map.obstacles.map(|o| if *o { '#' } else { '.' });
+        for pos in &self.visited {
+            chars[*pos] = 'X';
+        }
+        chars[self.map.guard] = self.map.facing.to_arrow();
+        chars.to_string()
+    }
+
+    fn image(&self, scale: usize) -> Image {
+        let mut image = Image::new(&self.map.obstacles, scale);
+        for pos in &self.visited {
+            image.paint(pos, Rgb::BLUE);
+        }
+        image.paint(&self.map.guard, Rgb::RED);
+        image
+    }
+
+    fn status(&self) -> String {
+        format!("Visited {} positions", self.visited.len())
+    }
+}
+
+fn tile(ch: char) -> Result<char, ParseError> {
+    match ch {
+        '.' | '#' | '^' => Ok(ch),
+        _ => Err(ParseError::new("a tile (., # or ^)", ch.to_string()))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    input.parse()
+}
+
+/// Every line which isn't a row of tiles as wide as the first, or if they are all fine, a missing guard
+pub fn check(input: &str) -> Vec<ParseError> {
+    common::check(Grid::check(input, tile), || parse(input))
+}
+
+/// The number of distinct positions the guard visits before leaving the area
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let map = parse(input)?;
+    Ok(visited(&map).len())
+}
+
+/// The number of positions where one new obstacle would trap the guard in a loop
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let original = parse(input)?;
+    let mut positions_causing_loop = 0;
+    for p in obstacle_candidates(&original).iter() {
+        if original.with_obstacle(*p).ends_in_loop() {
+            positions_causing_loop += 1;
+        }
+    }
+    Ok(positions_causing_loop)
+}
@@ -0,0 +1,6 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day6::part1(input).unwrap(), 41);
+    assert_eq!(day6::part2(input).unwrap(), 6);
+}
@@ -0,0 +1,109 @@
+//! Day 7: Bridge Repair
+
+use std::str::FromStr;
+use common::{ParseError, check_lines, parse_lines, parse_number};
+
+/// A calibration equation, missing the operators between its operands
+#[derive(Clone)]
+pub struct Equation {
+    pub answer: usize,
+    pub operands: Vec<usize>
+}
+
+impl FromStr for Equation {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let sections: Vec<_> = line.split(": ").collect();
+        if sections.len() != 2 {
+            return Err(ParseError::new("an answer and operands separated by \": \"", line));
+        }
+        let answer = parse_number(line, sections[0])?;
+        let operands = sections[1].split(" ").map(|n| parse_number(line, n)).collect::<Result<_, _>>()?;
+        Ok(Self {
+            answer,
+            operands
+        })
+    }
+}
+
+#[derive(Clone)]
+enum Operation {
+    Plus,
+    Multiply,
+    Concat
+}
+
+impl Equation {
+    /// Whether some combination of + and * (and || when `with_concat`), evaluated left to right, makes the answer
+    pub fn solveable(&self, with_concat: bool) -> bool {
+        self.solve(self.operands[0], Vec::new(), with_concat).is_some()
+    }
+
+    fn solve(&self, so_far: usize, operations: Vec<Operation>, with_concat: bool) -> Option<Vec<Operation>> {
+        if operations.len() == self.operands.len() - 1 {
+            if so_far == self.answer {
+                // solved
+                Some(operations)
+            } else {
+                None
+            }
+        } else {
+            let next_number = self.operands[operations.len() + 1];
+            // try plus
+            let mut operations_with_plus = operations.clone();
+            operations_with_plus.push(Operation::Plus);
+            if let Some(solution) = self.solve(so_far + next_number, operations_with_plus, with_concat) {
+                return Some(solution);
+            }
+            // try multiply
+            let mut operations_with_multiply = operations.clone();
+            operations_with_multiply.push(Operation::Multiply);
+            if let Some(solution) = self.solve(so_far * next_number, operations_with_multiply, with_concat) {
+                return Some(solution);
+            }
+            // try concat
+            if with_concat {
+                let mut operations_with_concat = operations;
+                operations_with_concat.push(Operation::Concat);
+                if let Some(solution) = self.solve(Self::concat(so_far, next_number), operations_with_concat, with_concat) {
+                    return Some(solution);
+                }
+            }
+            None
+        }
+    }
+
+    fn concat(a: usize, b: usize) -> usize {
+        let a_s: String = a.to_string();
+        let b_s: String = b.to_string();
+        let ab_s: String = a_s + &b_s;
+        let ab: usize = ab_s.parse().unwrap();
+        ab
+    }
+}
+
+/// One equation per line
+pub fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+    parse_lines(input)
+}
+
+/// Every line which isn't an equation
+pub fn check(input: &str) -> Vec<ParseError> {
+    check_lines(input, Equation::from_str)
+}
+
+fn answer_sum(input: &str, with_concat: bool) -> Result<usize, ParseError> {
+    let equations = parse(input)?;
+    Ok(equations.iter().filter(|e| e.solveable(with_concat)).map(|e| e.answer).sum())
+}
+
+/// The sum of the answers of equations which can be solved with + and *
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    answer_sum(input, false)
+}
+
+/// The sum of the answers of equations which can be solved with +, * and ||
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    answer_sum(input, true)
+}
@@ -0,0 +1,6 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day7::part1(input).unwrap(), 3749);
+    assert_eq!(day7::part2(input).unwrap(), 11387);
+}
@@ -0,0 +1,133 @@
+//! Day 8: Resonant Collinearity
+
+use std::str::FromStr;
+use std::collections::HashMap;
+use itertools::Itertools;
+use std::collections::HashSet;
+use grid::{Grid, Image, Palette, Pos, Rgb, SignedPos, Size};
+use common::ParseError;
+
+/// The city, with the position and frequency of each antenna
+pub struct Map {
+    antennas: HashMap<Pos, Antenna>,
+    size: Size
+}
+
+/// An antenna's frequency, a letter or digit
+#[derive(Debug, PartialEq)]
+pub struct Antenna(pub char);
+
+impl Antenna {
+    fn iter() -> Vec<Antenna> {
+        let mut v = Vec::new();
+        for i in 0..26 {
+            if i < 10 {
+                v.push(Antenna((i + 48) as u8 as char));
+            }
+            v.push(Antenna((i + 65) as u8 as char));
+            v.push(Antenna((i + 97) as u8 as char));
+        }
+        v
+    }
+}
+
+impl Palette for Antenna {
+    fn colour(&self) -> Rgb {
+        // digits, then upper case, then lower case letters
+        let index = match self.0 {
+            '0'..='9' => self.0 as usize - '0' as usize,
+            'A'..='Z' => self.0 as usize - 'A' as usize + 10,
+            'a'..='z' => self.0 as usize - 'a' as usize + 36,
+            _ => 0
+        };
+        Rgb::indexed(index)
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let chars = Grid::parse(text, tile)?;
+        let mut antennas = HashMap::new();
+        for (pos, ch) in chars.iter() {
+            if *ch != '.' {
+                antennas.insert(pos, Antenna(*ch));
+            }
+        }
+        Ok(Self {
+            antennas,
+            size: chars.size()
+        })
+    }
+}
+
+impl Map {
+    /// Every position within the map where an antinode occurs
+    ///
+    /// With harmonics, antinodes occur at every multiple of the distance between antennas (including 0)
+    pub fn antinodes(&self, harmonics: bool) -> HashSet<Pos> {
+        let mut antinodes = HashSet::new();
+        for antenna in Antenna::iter() {
+            let locations: Vec<&Pos> = self.antennas.iter().filter(|(_k,v)| **v == antenna).map(|(k,_v)| k).collect();
+            for pair in locations.iter().permutations(2) {
+                let delta_row = pair[1].row as isize - pair[0].row as isize;
+                let delta_col = pair[1].col as isize - pair[0].col as isize;
+                let mut current = SignedPos::from(**pair[1]);
+                if !harmonics {
+                    current = current.delta(delta_row, delta_col);
+                }
+                while let Some(pos) = self.size.checked(&current) {
+                    antinodes.insert(pos);
+                    if !harmonics {
+                        break;
+                    }
+                    current = current.delta(delta_row, delta_col);
+                }
+            }
+        }
+        antinodes
+    }
+}
+
+fn tile(ch: char) -> Result<char, ParseError> {
+    if ch == '.' || ch.is_ascii_alphanumeric() {
+        Ok(ch)
+    } else {
+        Err(ParseError::new("an antenna (a letter or digit) or .", ch.to_string()))
+    }
+}
+
+/// The city, with every antinode (including harmonics) in grey and each antenna in its frequency's colour
+pub fn image(input: &str, scale: usize) -> Result<Image, ParseError> {
+    let map = parse(input)?;
+    let mut image = Image::new(&Grid::new(map.size, false), scale);
+    for pos in map.antinodes(true) {
+        image.paint(&pos, Rgb::GREY);
+    }
+    for (pos, antenna) in &map.antennas {
+        image.paint(pos, antenna.colour());
+    }
+    Ok(image)
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    input.parse()
+}
+
+/// Every line which isn't a row of antennas and empty space as wide as the first
+pub fn check(input: &str) -> Vec<ParseError> {
+    Grid::check(input, tile)
+}
+
+/// The number of positions with an antinode
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let map = parse(input)?;
+    Ok(map.antinodes(false).len())
+}
+
+/// The number of positions with an antinode, including harmonics
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let map = parse(input)?;
+    Ok(map.antinodes(true).len())
+}
@@ -0,0 +1,13 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day8::part1(input).unwrap(), 14);
+    assert_eq!(day8::part2(input).unwrap(), 34);
+}
+
+#[test]
+fn test_check() {
+    assert!(day8::check(include_str!("../test.txt")).is_empty());
+    let errors = day8::check("..a.\n.#..\n.A.\n....\n");
+    assert_eq!(errors.iter().map(|e| (e.line, e.column)).collect::<Vec<_>>(), vec![(Some(2), Some(2)), (Some(3), None)]);
+}
@@ -0,0 +1,18 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use day9::DiskMap;
+
+fn compact_by_file(c: &mut Criterion) {
+    let map: DiskMap = include_str!("../input.txt").parse().unwrap();
+    c.bench_function("day9 compact_by_file", |b| b.iter_batched(
+        || map.to_disk(),
+        |mut disk| disk.compact_by_file(),
+        BatchSize::LargeInput
+    ));
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = compact_by_file
+}
+criterion_main!(benches);
@@ -0,0 +1,215 @@
+//! Day 9: Disk Fragmenter
+
+use std::str::FromStr;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use common::ParseError;
+
+/// The dense format of a disk, alternating between the size of each file and the free space after it
+pub struct DiskMap {
+    used: Vec<usize>,
+    free: Vec<usize> // free.len==used.len()-1
+}
+
+/// Each block of a disk, either free or part of a file
+pub struct Disk {
+    blocks: Vec<Option<Block>>
+}
+
+/// A block of the file with this id
+#[derive(Eq, PartialEq)]
+pub struct Block {
+    pub id: usize
+}
+
+impl FromStr for DiskMap {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let line = text.trim_end(); // ignore a trailing line ending
+        let mut used = Vec::new();
+        let mut free = Vec::new();
+        let digits: Vec<usize> = line.chars().enumerate().map(|(i, c)| match c.to_digit(10) {
+            Some(d) => Ok(d as usize),
+            None => Err(ParseError::new("a digit", c.to_string()).with_column(i + 1))
+        }).collect::<Result<_, _>>()?;
+        let mut digits_iter = digits.iter();
+        loop {
+            used.push(*digits_iter.next().ok_or(ParseError::new("a digit", ""))?);
+            if let Some(next) = digits_iter.next() {
+                free.push(*next);
+            } else {
+                break;
+            }
+        }
+        if free.len() != used.len() - 1 {
+            return Err(ParseError::new("an odd number of digits", line));
+        }
+        Ok(Self {
+            used,
+            free
+        })
+    }
+}
+
+impl Display for Disk {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for b in &self.blocks {
+            if let Some(block) = b {
+                write!(f, "{}", block.id)?;
+            } else {
+                write!(f, ".")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl DiskMap {
+    /// Expand into the individual blocks, where each file's id is its position in the map
+    pub fn to_disk(&self) -> Disk {
+        let mut disk = Disk {
+            blocks: Vec::new()
+        };
+        for i in 0..self.used.len() - 1 {
+            disk.add_file(self.used[i], i);
+            disk.add_space(self.free[i]);
+        }
+        disk.add_file(self.used[self.used.len() - 1], self.used.len() - 1);
+        disk
+    }
+}
+
+impl Disk {
+    fn add_file(&mut self, size: usize, id: usize) {
+        for _i in 0..size {
+            self.blocks.push(Some(Block { id }));
+        }
+    }
+
+    fn add_space(&mut self, size: usize) {
+        for _i in 0..size {
+            self.blocks.push(None);
+        }
+    }
+
+    pub fn blocks(&self) -> &[Option<Block>] {
+        &self.blocks
+    }
+
+    /// Move blocks one at a time, from the end of the disk into the leftmost free block, until there are no gaps
+    pub fn compact_by_block(&mut self) {
+        let mut move_from = self.blocks.len() - 1;
+        let mut move_to = 0;
+        while move_from > move_to {
+            if self.blocks[move_from].is_none() {
+                move_from -= 1;
+                continue;
+            }
+            if self.blocks[move_to].is_some() {
+                move_to += 1;
+                continue;
+            }
+            self.blocks.swap(move_from, move_to);
+            move_to += 1;
+            move_from -= 1;
+        }
+    }
+
+    fn max_block_id(&self) -> usize {
+        if self.blocks.is_empty() {
+            panic!("Empty disk");
+        }
+        let mut max = 0;
+        for block in self.blocks.iter().flatten() {
+            if block.id > max {
+                max = block.id;
+            }
+        }
+        max
+    }
+
+    fn find_file(&self, id: usize) -> (usize, usize) {
+        let mut start = None;
+        for (i, b) in self.blocks.iter().enumerate() {
+            if let Some(s) = start {
+                if *b != Some(Block { id }) {
+                    return (s, i - s);
+                }
+            } else if *b == Some(Block { id }) {
+                start = Some(i);
+            }
+        }
+        if let Some(s) = start {
+            return (s, self.blocks.len() - s);
+        }
+        panic!("Block id not found")
+    }
+
+    fn has_space(&self, start: usize, length: usize) -> bool {
+        for i in start..(start+length) {
+            if self.blocks[i].is_some() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn swap_blocks(&mut self, mut a: usize, mut b: usize, length: usize) {
+        for _i in 0..length {
+            self.blocks.swap(a, b);
+            a += 1;
+            b += 1;
+        }
+    }
+
+    /// Move whole files, highest id first, into the leftmost free space big enough to fit them
+    pub fn compact_by_file(&mut self) {
+        let max = self.max_block_id();
+        for block_id in (0..(max+1)).rev() {
+            let (start, length) = self.find_file(block_id);
+            for move_to in 0..start {
+                if self.has_space(move_to, length) {
+                    self.swap_blocks(move_to, start, length);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// The sum of each block's position multiplied by its file id
+    pub fn checksum(&self) -> usize {
+        let mut sum = 0;
+        for i in 0..self.blocks.len() {
+            if let Some(block) = &self.blocks[i] {
+                sum += i * block.id;
+            }
+        }
+        sum
+    }
+}
+
+pub fn parse(input: &str) -> Result<DiskMap, ParseError> {
+    input.parse()
+}
+
+/// What's wrong with the only line, if anything
+pub fn check(input: &str) -> Vec<ParseError> {
+    parse(input).err().into_iter().collect()
+}
+
+/// The checksum after compacting block by block
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let map = parse(input)?;
+    let mut disk = map.to_disk();
+    disk.compact_by_block();
+    Ok(disk.checksum())
+}
+
+/// The checksum after compacting whole files
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let map = parse(input)?;
+    let mut disk = map.to_disk();
+    disk.compact_by_file();
+    Ok(disk.checksum())
+}
@@ -0,0 +1,6 @@
+#[test]
+fn test() {
+    let input = include_str!("../test.txt");
+    assert_eq!(day9::part1(input).unwrap(), 1928);
+    assert_eq!(day9::part2(input).unwrap(), 2858);
+}
@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+use proptest::prelude::*;
+use day9::Disk;
+
+// files of 1-9 blocks (as in the puzzle, no file is empty), each followed by 0-9 free blocks
+fn disk_map() -> impl Strategy<Value = String> {
+    (prop::collection::vec((1..=9u32, 0..=9u32), 0..40), 1..=9u32).prop_map(|(files, last)| {
+        let mut digits = String::new();
+        for (used, free) in files {
+            digits.push(char::from_digit(used, 10).unwrap());
+            digits.push(char::from_digit(free, 10).unwrap());
+        }
+        digits.push(char::from_digit(last, 10).unwrap());
+        digits
+    })
+}
+
+// the number of blocks of each file
+fn file_sizes(disk: &Disk) -> BTreeMap<usize, usize> {
+    let mut sizes = BTreeMap::new();
+    for block in disk.blocks().iter().flatten() {
+        *sizes.entry(block.id).or_insert(0) += 1;
+    }
+    sizes
+}
+
+fn last_used(disk: &Disk) -> Option<usize> {
+    disk.blocks().iter().rposition(|b| b.is_some())
+}
+
+proptest! {
+    #[test]
+    fn compact_by_block_leaves_no_gaps(digits in disk_map()) {
+        let map = day9::parse(&digits).unwrap();
+        let before = map.to_disk();
+        let mut after = map.to_disk();
+        after.compact_by_block();
+        prop_assert_eq!(file_sizes(&after), file_sizes(&before));
+        prop_assert_eq!(after.blocks().len(), before.blocks().len());
+        let used = after.blocks().iter().filter(|b| b.is_some()).count();
+        prop_assert!(after.blocks()[..used].iter().all(|b| b.is_some()));
+        prop_assert!(after.checksum() <= before.checksum());
+    }
+
+    #[test]
+    fn compact_by_file_only_moves_files_left(digits in disk_map()) {
+        let map = day9::parse(&digits).unwrap();
+        let before = map.to_disk();
+        let mut after = map.to_disk();
+        after.compact_by_file();
+        prop_assert_eq!(file_sizes(&after), file_sizes(&before));
+        prop_assert_eq!(after.blocks().len(), before.blocks().len());
+        prop_assert!(last_used(&after) <= last_used(&before));
+        prop_assert!(after.checksum() <= before.checksum());
+    }
+}
@@ -0,0 +1,11 @@
+use rand::Rng;
+use crate::Random;
+
+/// `size` pairs of 5 digit location ids
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        text.push_str(&format!("{}   {}\n", rng.gen_range(10000..100000), rng.gen_range(10000..100000)));
+    }
+    text
+}
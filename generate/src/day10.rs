@@ -0,0 +1,22 @@
+use rand::Rng;
+use crate::{Random, grid};
+
+/// A `size` by `size` topographic map, which mostly changes height by 1 between neighbours (so that there are trails)
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut heights = vec![vec![0; size]; size];
+    for row in 0..size {
+        for col in 0..size {
+            let previous = match (row, col) {
+                (0, 0) => rng.gen_range(0..10),
+                (0, _) => heights[row][col - 1],
+                _ => heights[row - 1][col]
+            };
+            heights[row][col] = if rng.gen_bool(0.8) {
+                (previous + if rng.gen_bool(0.5) { 1 } else { 9 }) % 10
+            } else {
+                rng.gen_range(0..10)
+            };
+        }
+    }
+    grid(size, size, |row, col| char::from_digit(heights[row][col], 10).unwrap())
+}
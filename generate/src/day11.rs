@@ -0,0 +1,11 @@
+use rand::Rng;
+use crate::Random;
+
+/// A line of `size` stones, engraved with numbers of up to 7 digits
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let stones: Vec<String> = (0..size).map(|_| {
+        let digits = rng.gen_range(1..=7);
+        rng.gen_range(0..10_usize.pow(digits)).to_string()
+    }).collect();
+    stones.join(" ")
+}
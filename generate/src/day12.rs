@@ -0,0 +1,10 @@
+use rand::Rng;
+use crate::{Random, grid};
+
+/// A `size` by `size` garden, where each plot grows the plant of the nearest of `size` randomly placed seeds
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let seeds: Vec<(usize, usize, char)> = (0..size.max(1)).map(|_| {
+        (rng.gen_range(0..size.max(1)), rng.gen_range(0..size.max(1)), rng.gen_range('A'..='Z'))
+    }).collect();
+    grid(size, size, |row, col| seeds.iter().min_by_key(|(r, c, _)| r.abs_diff(row) + c.abs_diff(col)).unwrap().2)
+}
@@ -0,0 +1,18 @@
+use rand::Rng;
+use crate::Random;
+
+/// `size` claw machines, about half of which can win their prize within 100 presses of each button
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut machines = Vec::new();
+    for _ in 0..size {
+        let a = (rng.gen_range(10..100), rng.gen_range(10..100));
+        let b = (rng.gen_range(10..100), rng.gen_range(10..100));
+        let (presses_a, presses_b) = (rng.gen_range(0..=100), rng.gen_range(0..=100));
+        let mut prize = (a.0 * presses_a + b.0 * presses_b, a.1 * presses_a + b.1 * presses_b);
+        if rng.gen_bool(0.5) {
+            prize.0 += 1;
+        }
+        machines.push(format!("Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n", a.0, a.1, b.0, b.1, prize.0, prize.1));
+    }
+    machines.join("\n")
+}
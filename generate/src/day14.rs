@@ -0,0 +1,14 @@
+use rand::Rng;
+use crate::Random;
+
+const WIDTH: isize = 101;
+const HEIGHT: isize = 103;
+
+/// `size` robots anywhere in the 101 by 103 bathroom, moving in any direction
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut text = format!("{},{}\n\n", WIDTH, HEIGHT);
+    for _ in 0..size {
+        text.push_str(&format!("p={},{} v={},{}\n", rng.gen_range(0..WIDTH), rng.gen_range(0..HEIGHT), rng.gen_range(-WIDTH + 1..WIDTH), rng.gen_range(-HEIGHT + 1..HEIGHT)));
+    }
+    text
+}
@@ -0,0 +1,27 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::{Random, grid};
+
+/// A `size` by `size` walled warehouse full of boxes, then `size * 400` moves for the robot
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let size = size.max(3);
+    let robot = (rng.gen_range(1..size - 1), rng.gen_range(1..size - 1));
+    let mut text = grid(size, size, |row, col| if row == 0 || col == 0 || row == size - 1 || col == size - 1 {
+        '#'
+    } else if (row, col) == robot {
+        '@'
+    } else {
+        match rng.gen_range(0..20) {
+            0 => '#',
+            1..=5 => 'O',
+            _ => '.'
+        }
+    });
+    let moves: Vec<char> = (0..(size * 400)).map(|_| *['<', '>', '^', 'v'].choose(rng).unwrap()).collect();
+    for line in moves.chunks(1000) {
+        text.push('\n');
+        text.extend(line);
+    }
+    text.push('\n');
+    text
+}
@@ -0,0 +1,22 @@
+use rand::Rng;
+use crate::{Random, grid};
+use crate::day20::perfect_maze;
+
+/// A maze of `size` by `size` cells (so `2 * size + 1` characters square), with extra gaps so that there are several best paths
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let size = size.max(2);
+    let open = perfect_maze(rng, size);
+    let width = 2 * size + 1;
+    grid(width, width, |row, col| if (row, col) == (width - 2, 1) {
+        'S'
+    } else if (row, col) == (1, width - 2) {
+        'E'
+    } else if open[row][col] {
+        '.'
+    } else if row > 0 && col > 0 && row < width - 1 && col < width - 1 && (row + col) % 2 == 1 && rng.gen_bool(0.05) {
+        // a gap in a wall between 2 cells
+        '.'
+    } else {
+        '#'
+    })
+}
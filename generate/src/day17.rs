@@ -0,0 +1,11 @@
+use rand::Rng;
+use crate::Random;
+
+/// A program shaped like the official ones (which outputs one number for each 3 bits of register A), with a register A of `size` 3 bit digits
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let a: u64 = rng.gen_range(0..8_u64.pow(size.clamp(1, 21) as u32));
+    // bst A, bxl ?, cdv B, bxc, bxl ?, out B, adv 3, jnz 0
+    let program = [2, 4, 1, rng.gen_range(0..8), 7, 5, 4, rng.gen_range(0..8), 1, rng.gen_range(0..8), 5, 5, 0, 3, 3, 0];
+    let program: Vec<String> = program.iter().map(|n| n.to_string()).collect();
+    format!("Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", a, program.join(","))
+}
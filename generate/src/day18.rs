@@ -0,0 +1,17 @@
+use rand::seq::SliceRandom;
+use crate::Random;
+
+/// Every byte of a `size` by `size` memory space (except the start and exit) falling in a random order
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let size = size.max(2);
+    let mut bytes = Vec::new();
+    for y in 0..size {
+        for x in 0..size {
+            if (x, y) != (0, 0) && (x, y) != (size - 1, size - 1) {
+                bytes.push(format!("{},{}\n", x, y));
+            }
+        }
+    }
+    bytes.shuffle(rng);
+    bytes.concat()
+}
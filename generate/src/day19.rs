@@ -0,0 +1,33 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::Random;
+
+const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+fn stripes(rng: &mut Random, length: usize) -> String {
+    (0..length).map(|_| *COLORS.choose(rng).unwrap()).collect()
+}
+
+/// `size` towel patterns and `size` designs, most of which are made from the towels
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+    let towels: Vec<String> = (0..size).map(|_| {
+        let length = rng.gen_range(2..=8);
+        stripes(rng, length)
+    }).collect();
+    let mut text = towels.join(", ");
+    text.push('\n');
+    for _ in 0..size {
+        text.push('\n');
+        if rng.gen_bool(0.7) {
+            for _ in 0..rng.gen_range(3..=10) {
+                text.push_str(towels.choose(rng).unwrap());
+            }
+        } else {
+            let length = rng.gen_range(20..=60);
+            text.push_str(&stripes(rng, length));
+        }
+    }
+    text.push('\n');
+    text
+}
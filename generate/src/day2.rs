@@ -0,0 +1,20 @@
+use rand::Rng;
+use crate::Random;
+
+/// `size` reports of 5 to 8 levels, which mostly move steadily up or down (so that some are safe)
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        let increasing = rng.gen_bool(0.5);
+        let mut level: isize = rng.gen_range(10..90);
+        let mut levels = Vec::new();
+        for _ in 0..rng.gen_range(5..=8) {
+            levels.push(level.to_string());
+            let step = if rng.gen_bool(0.9) { rng.gen_range(1..=3) } else { rng.gen_range(-2..=6) };
+            level = (level + if increasing { step } else { -step }).max(1);
+        }
+        text.push_str(&levels.join(" "));
+        text.push('\n');
+    }
+    text
+}
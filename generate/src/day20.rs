@@ -0,0 +1,64 @@
+use std::collections::{HashMap, VecDeque};
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::{Random, grid};
+
+/// A maze of `size` by `size` cells (so `2 * size + 1` characters square), with exactly one path between any two cells
+pub fn perfect_maze(rng: &mut Random, size: usize) -> Vec<Vec<bool>> {
+    let width = 2 * size + 1;
+    let mut open = vec![vec![false; width]; width];
+    let mut stack = vec![(rng.gen_range(0..size), rng.gen_range(0..size))];
+    open[2 * stack[0].0 + 1][2 * stack[0].1 + 1] = true;
+    while let Some(&(row, col)) = stack.last() {
+        let mut unvisited = Vec::new();
+        if row > 0 { unvisited.push((row - 1, col)); }
+        if col > 0 { unvisited.push((row, col - 1)); }
+        if row + 1 < size { unvisited.push((row + 1, col)); }
+        if col + 1 < size { unvisited.push((row, col + 1)); }
+        unvisited.retain(|(r, c)| !open[2 * r + 1][2 * c + 1]);
+        if let Some(&(r, c)) = unvisited.choose(rng) {
+            open[row + r + 1][col + c + 1] = true; // the wall between them
+            open[2 * r + 1][2 * c + 1] = true;
+            stack.push((r, c));
+        } else {
+            stack.pop();
+        }
+    }
+    open
+}
+
+/// A single corridor winding through a maze of `size` by `size` cells, from a random start to the furthest point from it
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let size = size.max(2);
+    let open = perfect_maze(rng, size);
+    let width = 2 * size + 1;
+    let start = (2 * rng.gen_range(0..size) + 1, 2 * rng.gen_range(0..size) + 1);
+    let mut previous = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    let mut end = start;
+    while let Some((row, col)) = queue.pop_front() {
+        end = (row, col);
+        for next in [(row - 1, col), (row + 1, col), (row, col - 1), (row, col + 1)] {
+            if open[next.0][next.1] && next != start && !previous.contains_key(&next) {
+                previous.insert(next, (row, col));
+                queue.push_back(next);
+            }
+        }
+    }
+    let mut track = vec![vec![false; width]; width];
+    let mut pos = end;
+    track[pos.0][pos.1] = true;
+    while let Some(&p) = previous.get(&pos) {
+        track[p.0][p.1] = true;
+        pos = p;
+    }
+    grid(width, width, |row, col| if (row, col) == start {
+        'S'
+    } else if (row, col) == end {
+        'E'
+    } else if track[row][col] {
+        '.'
+    } else {
+        '#'
+    })
+}
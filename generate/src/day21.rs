@@ -0,0 +1,11 @@
+use rand::Rng;
+use crate::Random;
+
+/// `size` door codes of 3 digits then A
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        text.push_str(&format!("{:03}A\n", rng.gen_range(0..1000)));
+    }
+    text
+}
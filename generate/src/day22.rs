@@ -0,0 +1,11 @@
+use rand::Rng;
+use crate::Random;
+
+/// `size` buyers' initial secret numbers
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        text.push_str(&format!("{}\n", rng.gen_range(1..16777216)));
+    }
+    text
+}
@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::Random;
+
+/// A network of `size` computers (up to 676), each connected to about 13 others, hiding one LAN party of 13
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut names: Vec<String> = ('a'..='z').flat_map(|a| ('a'..='z').map(move |b| format!("{}{}", a, b))).collect();
+    names.shuffle(rng);
+    names.truncate(size.clamp(2, names.len()));
+    let mut connections = HashSet::new();
+    let mut connect = |a: usize, b: usize| if a != b {
+        connections.insert((a.min(b), a.max(b)));
+    };
+    let party: Vec<usize> = (0..names.len().min(13)).collect();
+    for a in &party {
+        for b in &party {
+            connect(*a, *b);
+        }
+    }
+    for a in 0..names.len() {
+        for _ in 0..6 {
+            connect(a, rng.gen_range(0..names.len()));
+        }
+    }
+    let mut connections: Vec<_> = connections.into_iter().collect();
+    connections.sort(); // the set's order isn't seeded
+    connections.shuffle(rng);
+    let lines: Vec<String> = connections.into_iter().map(|(a, b)| if rng.gen_bool(0.5) {
+        format!("{}-{}\n", names[a], names[b])
+    } else {
+        format!("{}-{}\n", names[b], names[a])
+    }).collect();
+    lines.concat()
+}
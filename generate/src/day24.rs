@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::Random;
+
+/// A ripple carry adder of two `size` bit numbers, with random inputs and internal wire names
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let size = size.clamp(2, 99);
+    let mut used = HashSet::new();
+    let mut wire = || loop {
+        let name: String = (0..3).map(|_| rng.gen_range('a'..='w')).collect();
+        if used.insert(name.clone()) {
+            return name;
+        }
+    };
+    let mut gates = vec!["x00 XOR y00 -> z00".to_string()];
+    let mut carry = wire();
+    gates.push(format!("x00 AND y00 -> {}", carry));
+    for i in 1..size {
+        let (sum, direct, indirect) = (wire(), wire(), wire());
+        let next_carry = if i == size - 1 { format!("z{:02}", size) } else { wire() };
+        gates.push(format!("x{:02} XOR y{:02} -> {}", i, i, sum));
+        gates.push(format!("x{:02} AND y{:02} -> {}", i, i, direct));
+        gates.push(format!("{} XOR {} -> z{:02}", sum, carry, i));
+        gates.push(format!("{} AND {} -> {}", carry, sum, indirect));
+        gates.push(format!("{} OR {} -> {}", direct, indirect, next_carry));
+        carry = next_carry;
+    }
+    gates.shuffle(rng);
+    let mut text = String::new();
+    for input in ['x', 'y'] {
+        for i in 0..size {
+            text.push_str(&format!("{}{:02}: {}\n", input, i, rng.gen_range(0..2)));
+        }
+    }
+    text.push('\n');
+    text.push_str(&gates.join("\n"));
+    text.push('\n');
+    text
+}
@@ -0,0 +1,19 @@
+use rand::Rng;
+use crate::Random;
+
+/// `size` schematics, each a random lock or key with pins of random heights
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut schematics = Vec::new();
+    for _ in 0..size {
+        let lock = rng.gen_bool(0.5);
+        let heights: Vec<usize> = (0..5).map(|_| rng.gen_range(0..=5)).collect();
+        let mut rows: Vec<String> = (0..7).map(|row| {
+            // a lock's pins hang from row 0, a key's are counted up from row 6
+            let from_edge = if lock { row } else { 6 - row };
+            heights.iter().map(|h| if from_edge <= *h { '#' } else { '.' }).collect()
+        }).collect();
+        rows.push(String::new());
+        schematics.push(rows.join("\n"));
+    }
+    schematics.join("\n")
+}
@@ -0,0 +1,25 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::Random;
+
+const NOISE: [&str; 12] = ["select()", "why()", "from()", "who(2,3)", "mul(4*", "mul(6,9!", "?(12,34)", "mul ( 2 , 4 )", "%&", "]", "@^", "don't"];
+
+/// `size` instructions (mostly `mul`, with some `do()` and `don't()`) mixed with corrupted noise, over a few lines
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut text = String::new();
+    for i in 0..size {
+        match rng.gen_range(0..10) {
+            0 => text.push_str("do()"),
+            1 => text.push_str("don't()"),
+            _ => text.push_str(&format!("mul({},{})", rng.gen_range(1..1000), rng.gen_range(1..1000)))
+        }
+        for _ in 0..rng.gen_range(0..3) {
+            text.push_str(NOISE.choose(rng).unwrap());
+        }
+        if i % 150 == 149 {
+            text.push('\n');
+        }
+    }
+    text.push('\n');
+    text
+}
@@ -0,0 +1,7 @@
+use rand::seq::SliceRandom;
+use crate::{Random, grid};
+
+/// A `size` by `size` word search of X, M, A and S
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    grid(size, size, |_, _| *['X', 'M', 'A', 'S'].choose(rng).unwrap())
+}
@@ -0,0 +1,32 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::Random;
+
+/// An ordering rule for every pair of `size` pages (so the rules are consistent), then `size * 4` updates of an odd number of those pages (about half of which are in order)
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let size = size.clamp(3, 90);
+    let mut pages: Vec<usize> = (10..100).collect();
+    pages.shuffle(rng);
+    pages.truncate(size);
+    let mut rules = Vec::new();
+    for i in 0..pages.len() {
+        for j in (i + 1)..pages.len() {
+            rules.push(format!("{}|{}", pages[i], pages[j]));
+        }
+    }
+    rules.shuffle(rng);
+    let mut text = rules.join("\n");
+    text.push_str("\n\n");
+    for _ in 0..(size * 4) {
+        let length = rng.gen_range(1..=(size - 1) / 2) * 2 + 1;
+        let mut update: Vec<usize> = pages.choose_multiple(rng, length).cloned().collect();
+        if rng.gen_bool(0.5) {
+            // already in the right order
+            update.sort_by_key(|u| pages.iter().position(|p| p == u));
+        }
+        let update: Vec<String> = update.iter().map(|p| p.to_string()).collect();
+        text.push_str(&update.join(","));
+        text.push('\n');
+    }
+    text
+}
@@ -0,0 +1,21 @@
+use rand::Rng;
+use crate::{Random, grid};
+use day6::Map;
+
+/// A `size` by `size` lab with scattered obstructions, where the guard doesn't already walk in a loop
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    loop {
+        let guard = (rng.gen_range(0..size), rng.gen_range(0..size));
+        let text = grid(size, size, |row, col| if (row, col) == guard {
+            '^'
+        } else if rng.gen_bool(0.05) {
+            '#'
+        } else {
+            '.'
+        });
+        let mut map: Map = text.parse().unwrap();
+        if !map.ends_in_loop() {
+            return text;
+        }
+    }
+}
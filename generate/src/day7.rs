@@ -0,0 +1,25 @@
+use rand::Rng;
+use crate::Random;
+
+/// `size` equations of 2 to 8 operands, about half of which can be made true with + and * (or ||)
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        // small operands keep every possible answer within a usize
+        let operands: Vec<usize> = (0..rng.gen_range(2..=8)).map(|_| rng.gen_range(1..100)).collect();
+        let mut answer = operands[0];
+        for n in &operands[1..] {
+            answer = match rng.gen_range(0..3) {
+                0 => answer + n,
+                1 => answer * n,
+                _ => format!("{}{}", answer, n).parse().unwrap()
+            };
+        }
+        if rng.gen_bool(0.5) {
+            answer += 1;
+        }
+        let operands: Vec<String> = operands.iter().map(|n| n.to_string()).collect();
+        text.push_str(&format!("{}: {}\n", answer, operands.join(" ")));
+    }
+    text
+}
@@ -0,0 +1,14 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::{Random, grid};
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A `size` by `size` map with a few antennas of each frequency
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    grid(size, size, |_, _| if rng.gen_bool(0.08) {
+        *FREQUENCIES.choose(rng).unwrap() as char
+    } else {
+        '.'
+    })
+}
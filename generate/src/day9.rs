@@ -0,0 +1,15 @@
+use rand::Rng;
+use crate::Random;
+
+/// A disk map of `size` files, each between 1 and 9 blocks, separated by up to 9 free blocks
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut text = String::new();
+    for i in 0..size.max(1) {
+        if i != 0 {
+            text.push_str(&rng.gen_range(0..10).to_string());
+        }
+        text.push_str(&rng.gen_range(1..10).to_string());
+    }
+    text.push('\n');
+    text
+}
@@ -0,0 +1,93 @@
+use std::fmt::Debug;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+/// A seeded random number generator, which produces the same values on every platform and rand version
+pub type Random = ChaCha8Rng;
+
+/// Generates a valid puzzle input, where the meaning of size depends on the day (eg. lines, or width of a grid)
+pub type Generator = fn(&mut Random, usize) -> String;
+
+/// The generator for a day, and the size of the official input
+pub fn generator(day: usize) -> Option<(Generator, usize)> {
+    match day {
+        1 => Some((day1::generate, 1000)),
+        2 => Some((day2::generate, 1000)),
+        3 => Some((day3::generate, 700)),
+        4 => Some((day4::generate, 140)),
+        5 => Some((day5::generate, 49)),
+        6 => Some((day6::generate, 130)),
+        7 => Some((day7::generate, 850)),
+        8 => Some((day8::generate, 50)),
+        9 => Some((day9::generate, 10000)),
+        10 => Some((day10::generate, 50)),
+        11 => Some((day11::generate, 8)),
+        12 => Some((day12::generate, 140)),
+        13 => Some((day13::generate, 320)),
+        14 => Some((day14::generate, 500)),
+        15 => Some((day15::generate, 50)),
+        16 => Some((day16::generate, 70)),
+        17 => Some((day17::generate, 16)),
+        18 => Some((day18::generate, 71)),
+        19 => Some((day19::generate, 400)),
+        20 => Some((day20::generate, 70)),
+        21 => Some((day21::generate, 5)),
+        22 => Some((day22::generate, 2000)),
+        23 => Some((day23::generate, 520)),
+        24 => Some((day24::generate, 45)),
+        25 => Some((day25::generate, 500)),
+        _ => None
+    }
+}
+
+/// Generate an input for a day, which will always be the same for the same seed and size
+pub fn generate(day: usize, seed: u64, size: usize) -> Option<String> {
+    let (generator, _) = generator(day)?;
+    Some(generator(&mut Random::seed_from_u64(seed), size))
+}
+
+/// A grid of characters, one row per line
+fn grid<F: FnMut(usize, usize) -> char>(rows: usize, cols: usize, mut f: F) -> String {
+    let mut text = String::new();
+    for row in 0..rows {
+        for col in 0..cols {
+            text.push(f(row, col));
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Assert that two ways of solving a day agree on the input generated from each of the first `seeds` seeds, for differential tests
+pub fn assert_agree<T: PartialEq + Debug, A: Fn(&str) -> T, B: Fn(&str) -> T>(day: usize, size: usize, seeds: u64, a: A, b: B) {
+    for seed in 0..seeds {
+        let input = generate(day, seed, size).unwrap_or_else(|| panic!("Day {} has no generator", day));
+        assert_eq!(a(&input), b(&input), "with the input of `cargo run -p generate -- --day {} --seed {} --size {}`", day, seed, size);
+    }
+}
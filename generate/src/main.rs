@@ -0,0 +1,38 @@
+use std::env;
+use rand::SeedableRng;
+use generate::Random;
+
+const USAGE: &str = "Usage:
+    generate --day N [--size S] [--seed S]
+(size defaults to the size of the official input, seed defaults to 0)";
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let result = parse(&args[1..]).and_then(|(day, size, seed)| {
+        let (generator, default_size) = generate::generator(day).ok_or(format!("No generator for day {}", day))?;
+        print!("{}", generator(&mut Random::seed_from_u64(seed), size.unwrap_or(default_size)));
+        Ok(())
+    });
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn parse(args: &[String]) -> Result<(usize, Option<usize>, u64), String> {
+    let mut day = None;
+    let mut size = None;
+    let mut seed = 0;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for {}", arg))?;
+        let number: u64 = value.parse().map_err(|_| format!("Invalid number for {}: {}", arg, value))?;
+        match arg.as_str() {
+            "--day" => day = Some(number as usize),
+            "--size" => size = Some(number as usize),
+            "--seed" => seed = number,
+            other => return Err(format!("Unknown argument: {}\n{}", other, USAGE))
+        }
+    }
+    Ok((day.ok_or(USAGE.to_string())?, size, seed))
+}
@@ -0,0 +1,82 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl Direction {
+    /// All four directions, clockwise from Up
+    pub const ALL: [Self; 4] = [
+        Self::Up,
+        Self::Right,
+        Self::Down,
+        Self::Left
+    ];
+
+    /// Parse an arrow character (`^`, `>`, `v`, `<`)
+    pub fn from_arrow(ch: char) -> Option<Self> {
+        match ch {
+            '^' => Some(Self::Up),
+            '>' => Some(Self::Right),
+            'v' => Some(Self::Down),
+            '<' => Some(Self::Left),
+            _ => None
+        }
+    }
+
+    pub fn to_arrow(&self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<'
+        }
+    }
+
+    /// (row, col) delta of a single step in this direction
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Self::Up => (-1, 0),
+            Self::Right => (0, 1),
+            Self::Down => (1, 0),
+            Self::Left => (0, -1)
+        }
+    }
+
+    pub fn rotate_right(&self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up
+        }
+    }
+
+    pub fn rotate_left(&self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down
+        }
+    }
+
+    pub fn reverse(&self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        *self == Self::Left || *self == Self::Right
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        !self.is_horizontal()
+    }
+}
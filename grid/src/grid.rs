@@ -0,0 +1,245 @@
+use std::str::FromStr;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::{Index, IndexMut};
+use common::ParseError;
+use crate::{Direction, Pos, SignedPos, Size};
+
+/// A rectangular 2D map of tiles, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    tiles: Vec<T>,
+    size: Size
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(size: Size, fill: T) -> Self {
+        Self {
+            tiles: vec![fill; size.area()],
+            size
+        }
+    }
+
+    /// Swap rows and columns
+    pub fn transpose(&self) -> Self {
+        let size = Size::new(self.size.cols, self.size.rows);
+        let tiles = size.positions().map(|p| self[Pos::new(p.col, p.row)].clone()).collect();
+        Self {
+            tiles,
+            size
+        }
+    }
+
+    /// Rotate a quarter turn clockwise
+    pub fn rotate_right(&self) -> Self {
+        let size = Size::new(self.size.cols, self.size.rows);
+        let tiles = size.positions().map(|p| self[Pos::new(self.size.rows - 1 - p.col, p.row)].clone()).collect();
+        Self {
+            tiles,
+            size
+        }
+    }
+
+    /// Rotate a quarter turn anti-clockwise
+    pub fn rotate_left(&self) -> Self {
+        let size = Size::new(self.size.cols, self.size.rows);
+        let tiles = size.positions().map(|p| self[Pos::new(p.col, self.size.cols - 1 - p.row)].clone()).collect();
+        Self {
+            tiles,
+            size
+        }
+    }
+
+    /// Copy out each row as its own Vec
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        (0..self.size.rows).map(|r| self.row(r).to_vec()).collect()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parse a grid from lines of text, converting each character into a tile
+    pub fn parse<F: FnMut(char) -> Result<T, ParseError>>(text: &str, mut tile: F) -> Result<Self, ParseError> {
+        let mut tiles = Vec::new();
+        let mut size = Size::default();
+        for line in text.lines() {
+            tiles.extend(parse_row(line, size.rows, size.cols, &mut tile).map_err(|e| e.with_line(size.rows + 1))?);
+            if size.rows == 0 {
+                size.cols = tiles.len();
+            }
+            size.rows += 1;
+        }
+        Ok(Self {
+            tiles,
+            size
+        })
+    }
+
+    /// Every line which wouldn't parse as a row of tiles (with its first bad tile), or isn't as wide as the first line, rather than stopping at the first
+    pub fn check<F: FnMut(char) -> Result<T, ParseError>>(text: &str, mut tile: F) -> Vec<ParseError> {
+        let cols = text.lines().next().map_or(0, |l| l.chars().count());
+        text.lines()
+            .enumerate()
+            .filter_map(|(row, line)| parse_row(line, row, cols, &mut tile).err().map(|e| e.with_line(row + 1)))
+            .collect()
+    }
+
+    /// A grid of rows which should all be as wide as the first, or an error at the first (1-based) row which isn't
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ParseError> {
+        let size = Size::new(rows.len(), rows.first().map(|r| r.len()).unwrap_or(0));
+        if let Some((row, tiles)) = rows.iter().enumerate().find(|(_, r)| r.len() != size.cols) {
+            return Err(ParseError::new(format!("{} columns", size.cols), format!("{} columns", tiles.len())).with_line(row + 1));
+        }
+        Ok(Self {
+            tiles: rows.into_iter().flatten().collect(),
+            size
+        })
+    }
+
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    pub fn rows(&self) -> usize {
+        self.size.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.size.cols
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.tiles[(row * self.size.cols)..((row + 1) * self.size.cols)]
+    }
+
+    pub fn get(&self, pos: &Pos) -> Option<&T> {
+        if self.size.contains(pos) {
+            Some(&self.tiles[self.index_of(pos)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: &Pos) -> Option<&mut T> {
+        if self.size.contains(pos) {
+            let index = self.index_of(pos);
+            Some(&mut self.tiles[index])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_signed(&self, pos: &SignedPos) -> Option<&T> {
+        self.get(&self.size.checked(pos)?)
+    }
+
+    pub fn set(&mut self, pos: &Pos, tile: T) {
+        self[*pos] = tile;
+    }
+
+    pub fn contains(&self, pos: &Pos) -> bool {
+        self.size.contains(pos)
+    }
+
+    pub fn contains_signed(&self, pos: &SignedPos) -> bool {
+        self.size.contains_signed(pos)
+    }
+
+    /// Move one step in a direction, or None if that would leave the grid
+    pub fn step(&self, pos: &Pos, direction: Direction) -> Option<Pos> {
+        self.size.step(pos, direction)
+    }
+
+    /// The (up to) 4 orthogonally adjacent positions within the grid
+    pub fn adjacent(&self, pos: &Pos) -> Vec<Pos> {
+        self.size.adjacent(pos)
+    }
+
+    /// The (up to) 8 surrounding positions within the grid, including diagonals
+    pub fn surrounding(&self, pos: &Pos) -> Vec<Pos> {
+        self.size.surrounding(pos)
+    }
+
+    /// Every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        self.size.positions()
+    }
+
+    /// Every position and its tile, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.size.positions().zip(self.tiles.iter())
+    }
+
+    /// The first position (row by row) whose tile matches
+    pub fn position<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<Pos> {
+        self.iter().find(|(_, t)| predicate(t)).map(|(p, _)| p)
+    }
+
+    /// All positions whose tile matches
+    pub fn positions_where<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Vec<Pos> {
+        self.iter().filter(|(_, t)| predicate(t)).map(|(p, _)| p).collect()
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            tiles: self.tiles.iter().map(f).collect(),
+            size: self.size
+        }
+    }
+
+    fn index_of(&self, pos: &Pos) -> usize {
+        pos.row * self.size.cols + pos.col
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        if !self.size.contains(&pos) {
+            panic!("{:?} is outside grid of {:?}", pos, self.size);
+        }
+        &self.tiles[self.index_of(&pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        if !self.size.contains(&pos) {
+            panic!("{:?} is outside grid of {:?}", pos, self.size);
+        }
+        let index = self.index_of(&pos);
+        &mut self.tiles[index]
+    }
+}
+
+/// One line of tiles, which should have the same number of columns as the first row (unless this is the first row)
+fn parse_row<T, F: FnMut(char) -> Result<T, ParseError>>(line: &str, row: usize, cols: usize, tile: &mut F) -> Result<Vec<T>, ParseError> {
+    let tiles = line.chars()
+        .enumerate()
+        .map(|(col, ch)| tile(ch).map_err(|e| e.with_column(col + 1)))
+        .collect::<Result<Vec<T>, ParseError>>()?;
+    if row > 0 && tiles.len() != cols {
+        return Err(ParseError::new(format!("{} columns", cols), format!("{} columns", tiles.len())));
+    }
+    Ok(tiles)
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text, Ok)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for row in 0..self.size.rows {
+            for tile in self.row(row) {
+                write!(f, "{}", tile)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use crate::{Grid, Pos};
+
+/// A colour, as red, green and blue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+    pub const GREY: Self = Self(128, 128, 128);
+    pub const RED: Self = Self(220, 40, 40);
+    pub const GREEN: Self = Self(40, 200, 60);
+    pub const BLUE: Self = Self(50, 90, 230);
+    pub const YELLOW: Self = Self(240, 210, 40);
+    pub const BROWN: Self = Self(150, 100, 50);
+
+    /// Part way from this colour to another, where 0.0 is this colour and 1.0 is the other
+    pub fn blend(self, other: Self, fraction: f64) -> Self {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * fraction).round() as u8;
+        Self(mix(self.0, other.0), mix(self.1, other.1), mix(self.2, other.2))
+    }
+
+    /// A bright colour for any index, where consecutive indexes get very different hues
+    pub fn indexed(index: usize) -> Self {
+        // stepping by the golden angle never lands close to a previous hue
+        let hue = (index as f64 * 137.508) % 360.0;
+        let x = 1.0 - ((hue / 60.0) % 2.0 - 1.0).abs();
+        let (r, g, b) = match (hue / 60.0) as usize {
+            0 => (1.0, x, 0.0),
+            1 => (x, 1.0, 0.0),
+            2 => (0.0, 1.0, x),
+            3 => (0.0, x, 1.0),
+            4 => (x, 0.0, 1.0),
+            _ => (1.0, 0.0, x)
+        };
+        let channel = |c: f64| (55.0 + c * 200.0) as u8;
+        Self(channel(r), channel(g), channel(b))
+    }
+}
+
+/// How to colour a type of tile when drawing a grid as an image
+pub trait Palette {
+    fn colour(&self) -> Rgb;
+}
+
+impl Palette for Rgb {
+    fn colour(&self) -> Rgb {
+        *self
+    }
+}
+
+// walls (or anything else which is either there or not)
+impl Palette for bool {
+    fn colour(&self) -> Rgb {
+        if *self { Rgb::GREY } else { Rgb::BLACK }
+    }
+}
+
+/// A picture of a grid, where each tile is a square of `scale` by `scale` pixels
+pub struct Image {
+    width: usize,
+    height: usize,
+    scale: usize,
+    pixels: Vec<Rgb>
+}
+
+impl Image {
+    pub fn new<T: Palette>(grid: &Grid<T>, scale: usize) -> Self {
+        Self::with_palette(grid, scale, T::colour)
+    }
+
+    pub fn with_palette<T, F: Fn(&T) -> Rgb>(grid: &Grid<T>, scale: usize, palette: F) -> Self {
+        let scale = scale.max(1);
+        let mut image = Self {
+            width: grid.cols() * scale,
+            height: grid.rows() * scale,
+            scale,
+            pixels: vec![Rgb::BLACK; grid.size().area() * scale * scale]
+        };
+        for (pos, tile) in grid.iter() {
+            image.paint(&pos, palette(tile));
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Colour the square of the tile at `pos` (eg. to draw a path or robot over the tiles)
+    pub fn paint(&mut self, pos: &Pos, colour: Rgb) {
+        for row in (pos.row * self.scale)..((pos.row + 1) * self.scale) {
+            let start = row * self.width + pos.col * self.scale;
+            self.pixels[start..(start + self.scale)].fill(colour);
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|p| [p.0, p.1, p.2]).collect()
+    }
+
+    /// Write as a binary PPM, which is simple enough for almost anything to read
+    pub fn write_ppm<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.bytes())
+    }
+
+    pub fn write_png<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&self.bytes()).map_err(io::Error::other)
+    }
+
+    /// Save to a file, as PNG or PPM depending on its extension
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let format = ImageFormat::of(path.as_ref())?;
+        let mut writer = BufWriter::new(File::create(path)?);
+        match format {
+            ImageFormat::Png => self.write_png(&mut writer)?,
+            ImageFormat::Ppm => self.write_ppm(&mut writer)?
+        }
+        writer.flush()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImageFormat {
+    Png,
+    Ppm
+}
+
+impl ImageFormat {
+    fn of(path: &Path) -> io::Result<Self> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => Ok(Self::Png),
+            Some("ppm") => Ok(Self::Ppm),
+            _ => Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{} should end in .png or .ppm", path.display())))
+        }
+    }
+}
+
+/// A sequence of images saved as numbered files next to each other, eg. `out.png` is saved as `out-00000.png`, `out-00001.png`, ..
+/// which other tools can join into a gif (eg. `ffmpeg -i out-%05d.png out.gif`)
+pub struct Frames {
+    path: PathBuf,
+    count: usize
+}
+
+impl Frames {
+    pub fn new<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        ImageFormat::of(&path)?;
+        Ok(Self {
+            path,
+            count: 0
+        })
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Save the next frame, returning where it was saved
+    pub fn save(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let stem = self.path.file_stem().and_then(|s| s.to_str()).unwrap_or("frame");
+        let extension = self.path.extension().and_then(|e| e.to_str()).unwrap_or("png");
+        let path = self.path.with_file_name(format!("{}-{:05}.{}", stem, self.count, extension));
+        image.save(&path)?;
+        self.count += 1;
+        Ok(path)
+    }
+}
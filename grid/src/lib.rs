@@ -0,0 +1,13 @@
+mod direction;
+mod grid;
+mod image;
+mod pos;
+mod simulation;
+mod size;
+
+pub use crate::direction::Direction;
+pub use crate::grid::Grid;
+pub use crate::image::{Frames, Image, Palette, Rgb};
+pub use crate::pos::{Pos, SignedPos};
+pub use crate::simulation::Simulation;
+pub use crate::size::Size;
@@ -0,0 +1,110 @@
+use crate::Direction;
+
+/// A position which is always inside a grid, 0-indexed from the top left
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize
+}
+
+/// A position which may be outside a grid (eg. off the top or left edge)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct SignedPos {
+    pub row: isize,
+    pub col: isize
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self {
+            row,
+            col
+        }
+    }
+
+    /// Move by (delta_row, delta_col), or None if that would go above or left of the origin
+    pub fn offset(&self, delta_row: isize, delta_col: isize) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_add_signed(delta_row)?,
+            col: self.col.checked_add_signed(delta_col)?
+        })
+    }
+
+    /// Move one step in a direction, or None if that would go above or left of the origin
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        let (delta_row, delta_col) = direction.delta();
+        self.offset(delta_row, delta_col)
+    }
+
+    /// The 4 orthogonally adjacent positions which don't go above or left of the origin
+    pub fn adjacent(&self) -> Vec<Self> {
+        Direction::ALL.iter().filter_map(|d| self.step(*d)).collect()
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl SignedPos {
+    pub fn new(row: isize, col: isize) -> Self {
+        Self {
+            row,
+            col
+        }
+    }
+
+    pub fn delta(&self, delta_row: isize, delta_col: isize) -> Self {
+        Self {
+            row: self.row + delta_row,
+            col: self.col + delta_col
+        }
+    }
+
+    pub fn step(&self, direction: Direction) -> Self {
+        let (delta_row, delta_col) = direction.delta();
+        self.delta(delta_row, delta_col)
+    }
+
+    /// The 4 orthogonally adjacent positions (up, left, down, right)
+    pub fn adjacent(&self) -> [Self; 4] {
+        [self.delta(-1, 0), self.delta(0, -1), self.delta(1, 0), self.delta(0, 1)]
+    }
+
+    /// The 8 surrounding positions, including diagonals
+    pub fn surrounding(&self) -> [Self; 8] {
+        [
+            self.delta(-1, -1), self.delta(-1, 0), self.delta(-1, 1),
+            self.delta(0, -1), self.delta(0, 1),
+            self.delta(1, -1), self.delta(1, 0), self.delta(1, 1)
+        ]
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<Pos> for SignedPos {
+    fn from(pos: Pos) -> Self {
+        Self {
+            row: pos.row as isize,
+            col: pos.col as isize
+        }
+    }
+}
+
+impl TryFrom<SignedPos> for Pos {
+    type Error = SignedPos;
+
+    fn try_from(pos: SignedPos) -> Result<Self, Self::Error> {
+        if pos.row < 0 || pos.col < 0 {
+            Err(pos)
+        } else {
+            Ok(Self {
+                row: pos.row as usize,
+                col: pos.col as usize
+            })
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::Image;
+
+/// A simulation which can be advanced one step at a time, and drawn after each step (eg. to animate it)
+pub trait Simulation {
+    /// Advance by one step, or return false (without changing anything) if the simulation has finished
+    fn step(&mut self) -> bool;
+
+    /// The current state, as lines of text
+    fn frame(&self) -> String;
+
+    /// The current state, as a picture with `scale` pixels per tile
+    fn image(&self, scale: usize) -> Image;
+
+    /// Anything else worth showing about the current state
+    fn status(&self) -> String {
+        String::new()
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Direction, Pos, SignedPos};
+
+/// The bounds of a grid, used for bounds-checked movement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Size {
+    pub rows: usize,
+    pub cols: usize
+}
+
+impl Size {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols
+        }
+    }
+
+    pub fn area(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    pub fn contains(&self, pos: &Pos) -> bool {
+        pos.row < self.rows && pos.col < self.cols
+    }
+
+    pub fn contains_signed(&self, pos: &SignedPos) -> bool {
+        pos.row >= 0 && pos.col >= 0 && (pos.row as usize) < self.rows && (pos.col as usize) < self.cols
+    }
+
+    /// Convert a signed position into a grid position, if it is within bounds
+    pub fn checked(&self, pos: &SignedPos) -> Option<Pos> {
+        if self.contains_signed(pos) {
+            Some(Pos {
+                row: pos.row as usize,
+                col: pos.col as usize
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Move one step in a direction, or None if that would leave the grid
+    pub fn step(&self, pos: &Pos, direction: Direction) -> Option<Pos> {
+        let next = pos.step(direction)?;
+        if self.contains(&next) {
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    /// The (up to) 4 orthogonally adjacent positions within the grid
+    pub fn adjacent(&self, pos: &Pos) -> Vec<Pos> {
+        Direction::ALL.iter().filter_map(|d| self.step(pos, *d)).collect()
+    }
+
+    /// The (up to) 8 surrounding positions within the grid, including diagonals
+    pub fn surrounding(&self, pos: &Pos) -> Vec<Pos> {
+        SignedPos::from(*pos).surrounding().iter().filter_map(|p| self.checked(p)).collect()
+    }
+
+    /// Every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| Pos { row, col }))
+    }
+}
@@ -0,0 +1,47 @@
+use grid::{Grid, Pos, Size};
+
+fn grid(text: &str) -> Grid<char> {
+    text.parse().unwrap()
+}
+
+#[test]
+fn test_rotate() {
+    let abc = grid("abc\ndef\n");
+    assert_eq!(abc.rotate_right().to_string(), "da\neb\nfc\n");
+    assert_eq!(abc.rotate_left().to_string(), "cf\nbe\nad\n");
+    assert_eq!(abc.rotate_right().rotate_left(), abc);
+    assert_eq!(abc.rotate_right().rotate_right().rotate_right(), abc.rotate_left());
+    assert_eq!(abc.rotate_right().size(), Size::new(3, 2));
+}
+
+#[test]
+fn test_transpose() {
+    let abc = grid("abc\ndef\n");
+    assert_eq!(abc.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(abc.transpose().transpose(), abc);
+    assert_eq!(abc.transpose()[Pos::new(2, 1)], abc[Pos::new(1, 2)]);
+}
+
+#[test]
+fn test_edges() {
+    let abc = grid("abc\ndef\n");
+    assert_eq!(abc.adjacent(&Pos::new(0, 0)), vec![Pos::new(0, 1), Pos::new(1, 0)]);
+    assert_eq!(abc.adjacent(&Pos::new(1, 2)), vec![Pos::new(0, 2), Pos::new(1, 1)]);
+    assert_eq!(abc.adjacent(&Pos::new(0, 1)).len(), 3);
+    assert_eq!(abc.surrounding(&Pos::new(0, 0)), vec![Pos::new(0, 1), Pos::new(1, 0), Pos::new(1, 1)]);
+    assert_eq!(abc.surrounding(&Pos::new(1, 2)), vec![Pos::new(0, 1), Pos::new(0, 2), Pos::new(1, 1)]);
+    assert_eq!(abc.surrounding(&Pos::new(0, 1)).len(), 5);
+    assert_eq!(grid("a\n").surrounding(&Pos::new(0, 0)), vec![]);
+}
+
+#[test]
+fn test_from_rows() {
+    let rows = vec![vec![1, 2], vec![3, 4]];
+    let grid = Grid::from_rows(rows.clone()).unwrap();
+    assert_eq!(grid.size(), Size::new(2, 2));
+    assert_eq!(grid.to_rows(), rows);
+    assert_eq!(Grid::<u8>::from_rows(vec![]).unwrap().size(), Size::new(0, 0));
+    // ragged rows are an error at the first row which isn't as wide as the first, rather than a panic
+    let error = Grid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5], vec![6, 7, 8]]).unwrap_err();
+    assert_eq!(error.to_string(), "line 3: expected 2 columns but found 1 columns");
+}
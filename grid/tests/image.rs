@@ -0,0 +1,81 @@
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use grid::{Frames, Grid, Image, Pos, Rgb, Size};
+
+// a grid of 2 rows and 3 columns, with one wall
+fn walls() -> Grid<bool> {
+    let mut grid = Grid::new(Size::new(2, 3), false);
+    grid.set(&Pos::new(0, 1), true);
+    grid
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("grid-image-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_ppm() {
+    let image = Image::new(&walls(), 2);
+    assert_eq!((image.width(), image.height()), (6, 4));
+    let mut bytes = Vec::new();
+    image.write_ppm(&mut bytes).unwrap();
+    let header = "P6\n6 4\n255\n";
+    assert!(bytes.starts_with(header.as_bytes()));
+    assert_eq!(bytes.len(), header.len() + 6 * 4 * 3);
+    // the wall is a 2x2 square of grey in the top middle
+    let pixel = |row: usize, col: usize| &bytes[(header.len() + (row * 6 + col) * 3)..][..3];
+    assert_eq!(pixel(0, 2), [128, 128, 128]);
+    assert_eq!(pixel(1, 3), [128, 128, 128]);
+    assert_eq!(pixel(0, 1), [0, 0, 0]);
+    assert_eq!(pixel(2, 2), [0, 0, 0]);
+}
+
+#[test]
+fn test_png() {
+    let mut image = Image::new(&walls(), 2);
+    image.paint(&Pos::new(1, 2), Rgb::RED);
+    let mut ppm = Vec::new();
+    image.write_ppm(&mut ppm).unwrap();
+    let mut png = Vec::new();
+    image.write_png(&mut png).unwrap();
+
+    let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    assert_eq!((info.width, info.height, info.color_type), (6, 4, png::ColorType::Rgb));
+    assert_eq!(&pixels[..info.buffer_size()], &ppm["P6\n6 4\n255\n".len()..]);
+}
+
+#[test]
+fn test_frames() {
+    let dir = temp_dir("frames");
+    let mut frames = Frames::new(dir.join("out.png")).unwrap();
+    let image = Image::new(&walls(), 1);
+    assert_eq!(frames.save(&image).unwrap(), dir.join("out-00000.png"));
+    assert_eq!(frames.save(&image).unwrap(), dir.join("out-00001.png"));
+    assert_eq!(frames.count(), 2);
+    assert!(dir.join("out-00001.png").exists());
+    assert!(!dir.join("out.png").exists());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_format() {
+    let dir = temp_dir("format");
+    let image = Image::new(&walls(), 1);
+    image.save(dir.join("out.ppm")).unwrap();
+    assert!(fs::read(dir.join("out.ppm")).unwrap().starts_with(b"P6\n"));
+    image.save(dir.join("out.png")).unwrap();
+    assert!(fs::read(dir.join("out.png")).unwrap().starts_with(b"\x89PNG"));
+    // anything else is rejected before a file is created
+    for name in ["out.gif", "out.PNG", "out"] {
+        assert_eq!(image.save(dir.join(name)).unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert_eq!(Frames::new(dir.join(name)).err().map(|e| e.kind()), Some(ErrorKind::InvalidInput));
+        assert!(!dir.join(name).exists());
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,43 @@
+use grid::{Direction, Pos, SignedPos, Size};
+
+#[test]
+fn test_rotate() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.rotate_right().rotate_left(), direction);
+        assert_eq!(direction.rotate_right().rotate_right(), direction.reverse());
+        assert_eq!(direction.rotate_left().rotate_left(), direction.reverse());
+        assert_ne!(direction.rotate_right().is_horizontal(), direction.is_horizontal());
+    }
+    assert_eq!(Direction::Up.rotate_right(), Direction::Right);
+    assert_eq!(Direction::Up.rotate_left(), Direction::Left);
+}
+
+#[test]
+fn test_step() {
+    let origin = Pos::new(0, 0);
+    // off the top or left edge of any grid
+    assert_eq!(origin.step(Direction::Up), None);
+    assert_eq!(origin.step(Direction::Left), None);
+    assert_eq!(origin.step(Direction::Down), Some(Pos::new(1, 0)));
+    assert_eq!(origin.adjacent(), vec![Pos::new(0, 1), Pos::new(1, 0)]);
+    // off the bottom or right edge only once the size is known
+    let size = Size::new(2, 3);
+    assert_eq!(size.step(&Pos::new(1, 2), Direction::Down), None);
+    assert_eq!(size.step(&Pos::new(1, 2), Direction::Right), None);
+    assert_eq!(size.step(&Pos::new(1, 2), Direction::Up), Some(Pos::new(0, 2)));
+    assert_eq!(size.step(&origin, Direction::Up), None);
+}
+
+#[test]
+fn test_signed() {
+    let pos = Pos::new(2, 3);
+    assert_eq!(SignedPos::from(pos), SignedPos::new(2, 3));
+    assert_eq!(Pos::try_from(SignedPos::from(pos)), Ok(pos));
+    assert_eq!(Pos::try_from(SignedPos::new(-1, 3)), Err(SignedPos::new(-1, 3)));
+    assert_eq!(Pos::try_from(SignedPos::new(0, -1)), Err(SignedPos::new(0, -1)));
+    assert_eq!(SignedPos::new(0, 0).step(Direction::Up), SignedPos::new(-1, 0));
+    let size = Size::new(2, 3);
+    assert_eq!(size.checked(&SignedPos::new(1, 2)), Some(Pos::new(1, 2)));
+    assert_eq!(size.checked(&SignedPos::new(2, 2)), None);
+    assert_eq!(size.checked(&SignedPos::new(-1, 0)), None);
+}
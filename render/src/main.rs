@@ -0,0 +1,138 @@
+use std::env;
+use common::{ParseError, read_input};
+use grid::{Frames, Image, Simulation};
+
+const USAGE: &str = "Usage:
+    render --day 4|6|8|10|12|14|15|16|18|20 [--input FILE|-] [--output FILE] [--scale S] [--big]
+    render --day 6|14|15|18 --frames [--steps N] [--every N] [--input FILE|-] [--output FILE] [--scale S] [--big]
+(input defaults to dayN/input.txt, output defaults to dayN.png (.png or .ppm), scale defaults to 4 pixels per tile)
+Simulations are drawn once they finish (or after --steps), or with --frames as numbered images of every (--every) step";
+
+struct Options {
+    day: usize,
+    input: Option<String>,
+    output: Option<String>,
+    scale: usize,
+    frames: bool,
+    steps: Option<usize>,
+    every: usize,
+    big: bool
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if let Err(e) = parse(&args[1..]).and_then(run) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn parse(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        day: 0,
+        input: None,
+        output: None,
+        scale: 4,
+        frames: false,
+        steps: None,
+        every: 1,
+        big: false
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--frames" => options.frames = true,
+            "--big" => options.big = true,
+            "--input" | "--output" => {
+                let value = args.next().ok_or(format!("Missing value for {}", arg))?.clone();
+                if arg == "--input" {
+                    options.input = Some(value);
+                } else {
+                    options.output = Some(value);
+                }
+            },
+            "--day" | "--scale" | "--steps" | "--every" => {
+                let value = args.next().ok_or(format!("Missing value for {}", arg))?;
+                let number: usize = value.parse().map_err(|_| format!("Invalid number for {}: {}", arg, value))?;
+                match arg.as_str() {
+                    "--day" => options.day = number,
+                    "--scale" => options.scale = number,
+                    "--steps" => options.steps = Some(number),
+                    _ => options.every = number.max(1)
+                }
+            },
+            other => return Err(format!("Unknown argument: {}\n{}", other, USAGE))
+        }
+    }
+    if options.day == 0 {
+        return Err(USAGE.to_string());
+    }
+    Ok(options)
+}
+
+fn run(options: Options) -> Result<(), String> {
+    let filename = options.input.clone().unwrap_or(format!("day{}/input.txt", options.day));
+    let text = read_input(&filename).map_err(|_| format!("Error reading from {}", filename))?;
+    let invalid = |e: ParseError| e.in_file(&filename).to_string();
+    if options.big && options.day != 15 {
+        return Err("--big only applies to day 15".to_string());
+    }
+    match options.day {
+        6 => simulate(&options, day6::Patrol::new(text.parse().map_err(invalid)?)),
+        // the robots never stop, so they need to be told when to
+        14 if options.steps.is_none() => Err("Day 14 never finishes, please give --steps".to_string()),
+        14 => simulate(&options, text.parse::<day14::Map>().map_err(invalid)?),
+        15 if options.big => simulate(&options, day15::big_warehouse(&text).map_err(invalid)?),
+        15 => simulate(&options, day15::warehouse(&text).map_err(invalid)?),
+        18 => simulate(&options, day18::FallingBytes::new(&text).map_err(invalid)?),
+        4 | 8 | 10 | 12 | 16 | 20 if options.frames || options.steps.is_some() => Err(format!("Day {} is not a simulation, so has no steps or frames", options.day)),
+        4 => save(&options, &day4::image(&text, options.scale).map_err(invalid)?),
+        8 => save(&options, &day8::image(&text, options.scale).map_err(invalid)?),
+        10 => save(&options, &day10::image(&text, options.scale).map_err(invalid)?),
+        12 => save(&options, &day12::image(&text, options.scale).map_err(invalid)?),
+        16 => save(&options, &day16::image(&text, options.scale).map_err(invalid)?),
+        20 => save(&options, &day20::image(&text, options.scale).map_err(invalid)?),
+        day => Err(format!("No image for day {}", day))
+    }
+}
+
+fn output(options: &Options) -> String {
+    options.output.clone().unwrap_or(format!("day{}.png", options.day))
+}
+
+fn save(options: &Options, image: &Image) -> Result<(), String> {
+    let path = output(options);
+    image.save(&path).map_err(|e| format!("Error saving {}: {}", path, e))?;
+    println!("Saved {} ({}x{})", path, image.width(), image.height());
+    Ok(())
+}
+
+fn simulate<S: Simulation>(options: &Options, mut simulation: S) -> Result<(), String> {
+    let mut steps = 0;
+    if options.frames {
+        let path = output(options);
+        let error = |e| format!("Error saving frames of {}: {}", path, e);
+        let mut frames = Frames::new(&path).map_err(error)?;
+        frames.save(&simulation.image(options.scale)).map_err(error)?;
+        while advance(&mut simulation, &mut steps, options.steps) {
+            if steps % options.every == 0 {
+                frames.save(&simulation.image(options.scale)).map_err(error)?;
+            }
+        }
+        println!("Saved {} frames of {} steps like {}", frames.count(), steps, path);
+        Ok(())
+    } else {
+        while advance(&mut simulation, &mut steps, options.steps) {}
+        save(options, &simulation.image(options.scale))
+    }
+}
+
+// step the simulation, unless it has finished or reached the limit
+fn advance<S: Simulation>(simulation: &mut S, steps: &mut usize, limit: Option<usize>) -> bool {
+    if limit.is_some_and(|l| *steps >= l) || !simulation.step() {
+        false
+    } else {
+        *steps += 1;
+        true
+    }
+}
@@ -1,13 +1,16 @@
-use std::fs;
-use std::env;
+// src/lib.rs
+//! Day N: Title
+
 use std::str::FromStr;
+use common::{ParseError, check_lines, parse_lines};
 
-struct Object {
+/// What each line of the input describes
+pub struct Object {
     ...
 }
 
 impl FromStr for Object {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
         ...
@@ -17,16 +20,42 @@ impl FromStr for Object {
     }
 }
 
+/// One object per line
+pub fn parse(input: &str) -> Result<Vec<Object>, ParseError> {
+    parse_lines(input)
+}
+
+/// Every line which isn't an object
+pub fn check(input: &str) -> Vec<ParseError> {
+    check_lines(input, Object::from_str)
+}
+
+/// What part 1 asks for
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let objects = parse(input)?;
+    ...
+}
+
+/// What part 2 asks for
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    ...
+}
+
+// src/main.rs
+use std::env;
+use common::{check_flag, exit_check, log_flags, read_input_or_exit, STDIN};
+
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let objects: Vec<Object> = text.lines().map(|s| s.parse().unwrap()).collect();
-        ...
-        println!("Answer: {}", ...);
+    let (check, args) = check_flag(log_flags(env::args().collect()));
+    if args.len() <= 2 {
+        let filename = args.get(1).map_or(STDIN, |s| s.as_str());
+        let text = read_input_or_exit(filename);
+        if check {
+            exit_check(filename, dayN::check(&text));
+        }
+        println!("Part1: {}", dayN::part1(&text).unwrap_or_else(|e| e.exit(filename)));
+        println!("Part2: {}", dayN::part2(&text).unwrap_or_else(|e| e.exit(filename)));
     } else {
-        println!("Please provide 1 argument: Filename");
+        println!("Please provide 1 argument: Filename (or - for stdin, the default), with --check to only validate it");
     }
 }
\ No newline at end of file